@@ -0,0 +1,77 @@
+// Copyright (c) 2015-2016, Johan Sköld.
+// License: http://opensource.org/licenses/ISC
+
+extern crate bgfx;
+extern crate glutin;
+
+mod common;
+
+use bgfx::*;
+use common::{EventQueue, SecondaryWindow};
+
+fn example(events: EventQueue, secondary: SecondaryWindow) {
+    let mut width: u16 = 1280;
+    let mut height: u16 = 720;
+    let debug = DEBUG_TEXT;
+    let reset = RESET_VSYNC;
+
+    let bgfx = bgfx::init(RendererType::Default, None, None).unwrap();
+    bgfx.reset(width, height, reset);
+
+    // Enable debug text.
+    bgfx.set_debug(debug);
+
+    // Set view 0 and 1 clear state. View 0 renders to the main window, and view 1 to the
+    // secondary window.
+    let clear = CLEAR_COLOR | CLEAR_DEPTH;
+    bgfx.set_view_clear(0, clear, 0x303030ff, 1.0_f32, 0);
+    bgfx.set_view_clear(1, clear, 0x803030ff, 1.0_f32, 0);
+
+    // Create a frame buffer for the swap chain of the secondary window. The window is kept open
+    // until the example exits, so it outlives the frame buffer.
+    let (mut secondary_width, mut secondary_height) = secondary.size();
+    let mut frame_buffer = unsafe {
+        FrameBuffer::from_window(&bgfx,
+                                 secondary.handle(),
+                                 secondary_width,
+                                 secondary_height,
+                                 None)
+    };
+
+    while !events.handle_events(&bgfx, &mut width, &mut height, reset) {
+        // Recreate the secondary window's frame buffer if the window was resized.
+        if let Some((w, h)) = events.secondary_size() {
+            secondary_width = w;
+            secondary_height = h;
+            frame_buffer.resize(w, h);
+        }
+
+        // Set view 0 default viewport.
+        bgfx.set_view_rect(0, 0, 0, width, height);
+
+        // Set view 1 to render to the secondary window.
+        bgfx.set_view_frame_buffer(1, Some(&frame_buffer));
+        bgfx.set_view_rect(1, 0, 0, secondary_width, secondary_height);
+
+        // These dummy draw calls are here to make sure that both views are cleared
+        // if no other draw calls are submitted to them.
+        bgfx.touch(0);
+        bgfx.touch(1);
+
+        // Use debug font to print information about this example.
+        bgfx.dbg_text_clear(None, None);
+        bgfx.dbg_text_print(0, 1, 0x4f, "examples/22-windows.rs");
+        bgfx.dbg_text_print(0, 2, 0x6f, "Description: Rendering into multiple windows.");
+
+        // Advance to next frame. Rendering thread will be kicked to
+        // process submitted rendering primitives.
+        bgfx.frame(false);
+    }
+
+    // bgfx will automatically be shut down when the local `bgfx` binding
+    // goes out of scope.
+}
+
+fn main() {
+    common::run_example_with_secondary_window(1280, 720, 640, 360, example);
+}
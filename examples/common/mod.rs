@@ -10,6 +10,7 @@ use bgfx::{Bgfx, PlatformData, RenderFrame};
 use glutin::{Api, GlRequest, Window, WindowBuilder};
 
 use std;
+use std::cell::Cell;
 use std::env;
 use std::fs::File;
 use std::io::Read;
@@ -25,11 +26,15 @@ pub enum Event {
 
     /// Window size event.
     Size(u16, u16),
+
+    /// Secondary window size event.
+    SecondarySize(u16, u16),
 }
 
 /// Event queue for communicating with the render thread.
 pub struct EventQueue {
     event_rx: Receiver<Event>,
+    secondary_size: Cell<Option<(u16, u16)>>,
 }
 
 impl EventQueue {
@@ -54,16 +59,50 @@ impl EventQueue {
                     *height = h;
                     bgfx.reset(w, h, reset);
                 }
+                Event::SecondarySize(w, h) => self.secondary_size.set(Some((w, h))),
             }
         }
 
         should_close
     }
 
+    /// Takes the most recent size of the secondary window, if it has been resized since the last
+    /// call.
+    #[allow(dead_code)]
+    pub fn secondary_size(&self) -> Option<(u16, u16)> {
+        self.secondary_size.take()
+    }
+
+}
+
+/// Secondary window, created on the render thread and rendered to through a window frame buffer.
+pub struct SecondaryWindow {
+    handle: usize,
+    width: u16,
+    height: u16,
+}
+
+impl SecondaryWindow {
+
+    /// Gets the native handle of the window.
+    #[allow(dead_code)]
+    pub fn handle(&self) -> *mut std::os::raw::c_void {
+        self.handle as *mut std::os::raw::c_void
+    }
+
+    /// Gets the initial size of the window.
+    #[allow(dead_code)]
+    pub fn size(&self) -> (u16, u16) {
+        (self.width, self.height)
+    }
+
 }
 
-/// Process window events on the render thread.
-fn process_events(window: &Window, event_tx: &Sender<Event>) -> bool {
+/// Process window events on the render thread. Resize events are sent using `size_event`.
+fn process_events(window: &Window,
+                  event_tx: &Sender<Event>,
+                  size_event: fn(u16, u16) -> Event)
+                  -> bool {
     let mut should_close = false;
 
     for event in window.poll_events() {
@@ -73,7 +112,7 @@ fn process_events(window: &Window, event_tx: &Sender<Event>) -> bool {
                 event_tx.send(Event::Close).unwrap();
             }
             glutin::Event::Resized(w, h) => {
-                event_tx.send(Event::Size(w as u16, h as u16)).unwrap();
+                event_tx.send(size_event(w as u16, h as u16)).unwrap();
             }
             _ => {}
         }
@@ -129,15 +168,44 @@ fn init_bgfx_platform(window: &Window) {
         .unwrap();
 }
 
+/// Creates a window with the given size and title.
+fn create_window(width: u16, height: u16, title: &str) -> Window {
+    WindowBuilder::new()
+        .with_dimensions(width as u32, height as u32)
+        .with_gl(GlRequest::Specific(Api::OpenGl, (3, 1)))
+        .with_title(String::from(title))
+        .build()
+        .expect("Failed to create window")
+}
+
+#[allow(dead_code)]
 pub fn run_example<M>(width: u16, height: u16, main: M)
     where M: Send + 'static + FnOnce(EventQueue)
 {
-    let window = WindowBuilder::new()
-                     .with_dimensions(width as u32, height as u32)
-                     .with_gl(GlRequest::Specific(Api::OpenGl, (3, 1)))
-                     .with_title(String::from("BGFX"))
-                     .build()
-                     .expect("Failed to create window");
+    run(width, height, None, move |events, _| main(events));
+}
+
+/// Runs an example with an additional secondary window, which the example can render to through a
+/// window frame buffer.
+#[allow(dead_code)]
+pub fn run_example_with_secondary_window<M>(width: u16,
+                                            height: u16,
+                                            secondary_width: u16,
+                                            secondary_height: u16,
+                                            main: M)
+    where M: Send + 'static + FnOnce(EventQueue, SecondaryWindow)
+{
+    run(width,
+        height,
+        Some((secondary_width, secondary_height)),
+        move |events, secondary| main(events, secondary.unwrap()));
+}
+
+fn run<M>(width: u16, height: u16, secondary_size: Option<(u16, u16)>, main: M)
+    where M: Send + 'static + FnOnce(EventQueue, Option<SecondaryWindow>)
+{
+    let window = create_window(width, height, "BGFX");
+    let secondary = secondary_size.map(|(w, h)| (create_window(w, h, "BGFX (secondary)"), w, h));
 
     unsafe {
         window.make_current().unwrap();
@@ -152,13 +220,35 @@ pub fn run_example<M>(width: u16, height: u16, main: M)
     // Initialize this thread as the render thread by pumping it once *before* calling bgfx::init.
     bgfx::render_frame();
 
+    // Window handles can't be sent between threads, so pass the secondary window handle as an
+    // integer.
+    let secondary_window = secondary.as_ref().map(|&(ref secondary, w, h)| {
+        SecondaryWindow {
+            handle: unsafe { secondary.platform_window() as usize },
+            width: w,
+            height: h,
+        }
+    });
+
     // Spawn a new thread to use as the main thread.
     let main_thread = thread::spawn(move || {
-        main(EventQueue { event_rx: event_rx });
+        let events = EventQueue {
+            event_rx: event_rx,
+            secondary_size: Cell::new(None),
+        };
+        main(events, secondary_window);
     });
 
-    // Pump window events until the window is closed.
-    while !process_events(&window, &event_tx) {
+    // Pump window events until a window is closed.
+    loop {
+        let mut should_close = process_events(&window, &event_tx, Event::Size);
+        if let Some((ref secondary, _, _)) = secondary {
+            should_close |= process_events(secondary, &event_tx, Event::SecondarySize);
+        }
+        if should_close {
+            break;
+        }
+
         bgfx::render_frame();
     }
 
@@ -259,3 +259,451 @@ impl Default for ResetFlags {
         RESET_NONE
     }
 }
+
+bitflags! {
+    flags SubmitFlags: u8 {
+        const SUBMIT_EYE_LEFT = bgfx_sys::BGFX_SUBMIT_EYE_LEFT,
+        const SUBMIT_EYE_RIGHT = bgfx_sys::BGFX_SUBMIT_EYE_RIGHT,
+        const SUBMIT_EYE_MASK = bgfx_sys::BGFX_SUBMIT_EYE_MASK,
+        const SUBMIT_EYE_FIRST = bgfx_sys::BGFX_SUBMIT_EYE_FIRST,
+        const SUBMIT_RESERVED_SHIFT = bgfx_sys::BGFX_SUBMIT_RESERVED_SHIFT as u8,
+        const SUBMIT_RESERVED_MASK = bgfx_sys::BGFX_SUBMIT_RESERVED_MASK,
+    }
+}
+
+impl Default for SubmitFlags {
+    #[inline]
+    fn default() -> SubmitFlags {
+        SUBMIT_EYE_FIRST
+    }
+}
+
+bitflags! {
+    flags ViewFlags: u8 {
+        const VIEW_NONE = bgfx_sys::BGFX_VIEW_NONE,
+        const VIEW_STEREO = bgfx_sys::BGFX_VIEW_STEREO,
+    }
+}
+
+impl Default for ViewFlags {
+    #[inline]
+    fn default() -> ViewFlags {
+        VIEW_NONE
+    }
+}
+
+bitflags! {
+    flags HmdFlags: u8 {
+        const HMD_NONE = bgfx_sys::BGFX_HMD_NONE,
+        const HMD_DEVICE_RESOLUTION = bgfx_sys::BGFX_HMD_DEVICE_RESOLUTION,
+        const HMD_RENDERING = bgfx_sys::BGFX_HMD_RENDERING,
+    }
+}
+
+impl Default for HmdFlags {
+    #[inline]
+    fn default() -> HmdFlags {
+        HMD_NONE
+    }
+}
+
+bitflags! {
+    flags CapsFlags: u64 {
+        const CAPS_ALPHA_TO_COVERAGE = bgfx_sys::BGFX_CAPS_ALPHA_TO_COVERAGE,
+        const CAPS_BLEND_INDEPENDENT = bgfx_sys::BGFX_CAPS_BLEND_INDEPENDENT,
+        const CAPS_COMPUTE = bgfx_sys::BGFX_CAPS_COMPUTE,
+        const CAPS_CONSERVATIVE_RASTER = bgfx_sys::BGFX_CAPS_CONSERVATIVE_RASTER,
+        const CAPS_DRAW_INDIRECT = bgfx_sys::BGFX_CAPS_DRAW_INDIRECT,
+        const CAPS_FRAGMENT_DEPTH = bgfx_sys::BGFX_CAPS_FRAGMENT_DEPTH,
+        const CAPS_FRAGMENT_ORDERING = bgfx_sys::BGFX_CAPS_FRAGMENT_ORDERING,
+        const CAPS_GRAPHICS_DEBUGGER = bgfx_sys::BGFX_CAPS_GRAPHICS_DEBUGGER,
+        const CAPS_HIDPI = bgfx_sys::BGFX_CAPS_HIDPI,
+        const CAPS_HMD = bgfx_sys::BGFX_CAPS_HMD,
+        const CAPS_INDEX32 = bgfx_sys::BGFX_CAPS_INDEX32,
+        const CAPS_INSTANCING = bgfx_sys::BGFX_CAPS_INSTANCING,
+        const CAPS_OCCLUSION_QUERY = bgfx_sys::BGFX_CAPS_OCCLUSION_QUERY,
+        const CAPS_RENDERER_MULTITHREADED = bgfx_sys::BGFX_CAPS_RENDERER_MULTITHREADED,
+        const CAPS_SWAP_CHAIN = bgfx_sys::BGFX_CAPS_SWAP_CHAIN,
+        const CAPS_TEXTURE_2D_ARRAY = bgfx_sys::BGFX_CAPS_TEXTURE_2D_ARRAY,
+        const CAPS_TEXTURE_3D = bgfx_sys::BGFX_CAPS_TEXTURE_3D,
+        const CAPS_TEXTURE_BLIT = bgfx_sys::BGFX_CAPS_TEXTURE_BLIT,
+        const CAPS_TEXTURE_COMPARE_ALL = bgfx_sys::BGFX_CAPS_TEXTURE_COMPARE_ALL,
+        const CAPS_TEXTURE_COMPARE_LEQUAL = bgfx_sys::BGFX_CAPS_TEXTURE_COMPARE_LEQUAL,
+        const CAPS_TEXTURE_CUBE_ARRAY = bgfx_sys::BGFX_CAPS_TEXTURE_CUBE_ARRAY,
+        const CAPS_TEXTURE_DIRECT_ACCESS = bgfx_sys::BGFX_CAPS_TEXTURE_DIRECT_ACCESS,
+        const CAPS_TEXTURE_READ_BACK = bgfx_sys::BGFX_CAPS_TEXTURE_READ_BACK,
+        const CAPS_VERTEX_ATTRIB_HALF = bgfx_sys::BGFX_CAPS_VERTEX_ATTRIB_HALF,
+        const CAPS_VERTEX_ATTRIB_UINT10 = bgfx_sys::BGFX_CAPS_VERTEX_ATTRIB_UINT10,
+    }
+}
+
+impl Default for CapsFlags {
+    #[inline]
+    fn default() -> CapsFlags {
+        CapsFlags::empty()
+    }
+}
+
+bitflags! {
+    flags CapsFormatFlags: u16 {
+        const CAPS_FORMAT_TEXTURE_NONE = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_NONE,
+        const CAPS_FORMAT_TEXTURE_2D = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_2D,
+        const CAPS_FORMAT_TEXTURE_2D_SRGB = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_2D_SRGB,
+        const CAPS_FORMAT_TEXTURE_2D_EMULATED = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_2D_EMULATED,
+        const CAPS_FORMAT_TEXTURE_3D = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_3D,
+        const CAPS_FORMAT_TEXTURE_3D_SRGB = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_3D_SRGB,
+        const CAPS_FORMAT_TEXTURE_3D_EMULATED = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_3D_EMULATED,
+        const CAPS_FORMAT_TEXTURE_CUBE = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_CUBE,
+        const CAPS_FORMAT_TEXTURE_CUBE_SRGB = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_CUBE_SRGB,
+        const CAPS_FORMAT_TEXTURE_CUBE_EMULATED = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_CUBE_EMULATED,
+        const CAPS_FORMAT_TEXTURE_VERTEX = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_VERTEX,
+        const CAPS_FORMAT_TEXTURE_IMAGE = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_IMAGE,
+        const CAPS_FORMAT_TEXTURE_FRAMEBUFFER = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_FRAMEBUFFER,
+        const CAPS_FORMAT_TEXTURE_FRAMEBUFFER_MSAA =
+            bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_FRAMEBUFFER_MSAA,
+        const CAPS_FORMAT_TEXTURE_MSAA = bgfx_sys::BGFX_CAPS_FORMAT_TEXTURE_MSAA,
+    }
+}
+
+impl Default for CapsFormatFlags {
+    #[inline]
+    fn default() -> CapsFormatFlags {
+        CAPS_FORMAT_TEXTURE_NONE
+    }
+}
+
+bitflags! {
+    flags TextureFlags: u32 {
+        const TEXTURE_NONE = bgfx_sys::BGFX_TEXTURE_NONE,
+        const TEXTURE_U_MIRROR = bgfx_sys::BGFX_TEXTURE_U_MIRROR,
+        const TEXTURE_U_CLAMP = bgfx_sys::BGFX_TEXTURE_U_CLAMP,
+        const TEXTURE_U_BORDER = bgfx_sys::BGFX_TEXTURE_U_BORDER,
+        const TEXTURE_U_SHIFT = bgfx_sys::BGFX_TEXTURE_U_SHIFT,
+        const TEXTURE_U_MASK = bgfx_sys::BGFX_TEXTURE_U_MASK,
+        const TEXTURE_V_MIRROR = bgfx_sys::BGFX_TEXTURE_V_MIRROR,
+        const TEXTURE_V_CLAMP = bgfx_sys::BGFX_TEXTURE_V_CLAMP,
+        const TEXTURE_V_BORDER = bgfx_sys::BGFX_TEXTURE_V_BORDER,
+        const TEXTURE_V_SHIFT = bgfx_sys::BGFX_TEXTURE_V_SHIFT,
+        const TEXTURE_V_MASK = bgfx_sys::BGFX_TEXTURE_V_MASK,
+        const TEXTURE_W_MIRROR = bgfx_sys::BGFX_TEXTURE_W_MIRROR,
+        const TEXTURE_W_CLAMP = bgfx_sys::BGFX_TEXTURE_W_CLAMP,
+        const TEXTURE_W_BORDER = bgfx_sys::BGFX_TEXTURE_W_BORDER,
+        const TEXTURE_W_SHIFT = bgfx_sys::BGFX_TEXTURE_W_SHIFT,
+        const TEXTURE_W_MASK = bgfx_sys::BGFX_TEXTURE_W_MASK,
+        const TEXTURE_MIN_POINT = bgfx_sys::BGFX_TEXTURE_MIN_POINT,
+        const TEXTURE_MIN_ANISOTROPIC = bgfx_sys::BGFX_TEXTURE_MIN_ANISOTROPIC,
+        const TEXTURE_MIN_SHIFT = bgfx_sys::BGFX_TEXTURE_MIN_SHIFT,
+        const TEXTURE_MIN_MASK = bgfx_sys::BGFX_TEXTURE_MIN_MASK,
+        const TEXTURE_MAG_POINT = bgfx_sys::BGFX_TEXTURE_MAG_POINT,
+        const TEXTURE_MAG_ANISOTROPIC = bgfx_sys::BGFX_TEXTURE_MAG_ANISOTROPIC,
+        const TEXTURE_MAG_SHIFT = bgfx_sys::BGFX_TEXTURE_MAG_SHIFT,
+        const TEXTURE_MAG_MASK = bgfx_sys::BGFX_TEXTURE_MAG_MASK,
+        const TEXTURE_MIP_POINT = bgfx_sys::BGFX_TEXTURE_MIP_POINT,
+        const TEXTURE_MIP_SHIFT = bgfx_sys::BGFX_TEXTURE_MIP_SHIFT,
+        const TEXTURE_MIP_MASK = bgfx_sys::BGFX_TEXTURE_MIP_MASK,
+        const TEXTURE_RT = bgfx_sys::BGFX_TEXTURE_RT,
+        const TEXTURE_RT_MSAA_X2 = bgfx_sys::BGFX_TEXTURE_RT_MSAA_X2,
+        const TEXTURE_RT_MSAA_X4 = bgfx_sys::BGFX_TEXTURE_RT_MSAA_X4,
+        const TEXTURE_RT_MSAA_X8 = bgfx_sys::BGFX_TEXTURE_RT_MSAA_X8,
+        const TEXTURE_RT_MSAA_X16 = bgfx_sys::BGFX_TEXTURE_RT_MSAA_X16,
+        const TEXTURE_RT_MSAA_SHIFT = bgfx_sys::BGFX_TEXTURE_RT_MSAA_SHIFT,
+        const TEXTURE_RT_MSAA_MASK = bgfx_sys::BGFX_TEXTURE_RT_MSAA_MASK,
+        const TEXTURE_RT_WRITE_ONLY = bgfx_sys::BGFX_TEXTURE_RT_WRITE_ONLY,
+        const TEXTURE_RT_MASK = bgfx_sys::BGFX_TEXTURE_RT_MASK,
+        const TEXTURE_COMPARE_LESS = bgfx_sys::BGFX_TEXTURE_COMPARE_LESS,
+        const TEXTURE_COMPARE_LEQUAL = bgfx_sys::BGFX_TEXTURE_COMPARE_LEQUAL,
+        const TEXTURE_COMPARE_EQUAL = bgfx_sys::BGFX_TEXTURE_COMPARE_EQUAL,
+        const TEXTURE_COMPARE_GEQUAL = bgfx_sys::BGFX_TEXTURE_COMPARE_GEQUAL,
+        const TEXTURE_COMPARE_GREATER = bgfx_sys::BGFX_TEXTURE_COMPARE_GREATER,
+        const TEXTURE_COMPARE_NOTEQUAL = bgfx_sys::BGFX_TEXTURE_COMPARE_NOTEQUAL,
+        const TEXTURE_COMPARE_NEVER = bgfx_sys::BGFX_TEXTURE_COMPARE_NEVER,
+        const TEXTURE_COMPARE_ALWAYS = bgfx_sys::BGFX_TEXTURE_COMPARE_ALWAYS,
+        const TEXTURE_COMPARE_SHIFT = bgfx_sys::BGFX_TEXTURE_COMPARE_SHIFT,
+        const TEXTURE_COMPARE_MASK = bgfx_sys::BGFX_TEXTURE_COMPARE_MASK,
+        const TEXTURE_COMPUTE_WRITE = bgfx_sys::BGFX_TEXTURE_COMPUTE_WRITE,
+        const TEXTURE_SRGB = bgfx_sys::BGFX_TEXTURE_SRGB,
+        const TEXTURE_BLIT_DST = bgfx_sys::BGFX_TEXTURE_BLIT_DST,
+        const TEXTURE_READ_BACK = bgfx_sys::BGFX_TEXTURE_READ_BACK,
+        const TEXTURE_BORDER_COLOR_SHIFT = bgfx_sys::BGFX_TEXTURE_BORDER_COLOR_SHIFT,
+        const TEXTURE_BORDER_COLOR_MASK = bgfx_sys::BGFX_TEXTURE_BORDER_COLOR_MASK,
+        const TEXTURE_RESERVED_SHIFT = bgfx_sys::BGFX_TEXTURE_RESERVED_SHIFT,
+        const TEXTURE_RESERVED_MASK = bgfx_sys::BGFX_TEXTURE_RESERVED_MASK,
+        const TEXTURE_SAMPLER_BITS_MASK = bgfx_sys::BGFX_TEXTURE_SAMPLER_BITS_MASK,
+    }
+}
+
+impl Default for TextureFlags {
+    #[inline]
+    fn default() -> TextureFlags {
+        TEXTURE_NONE
+    }
+}
+
+/// Texture coordinate wrap mode.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum TextureWrap {
+    /// Repeat the texture.
+    Repeat = 0,
+
+    /// Repeat the texture, mirroring every other repetition.
+    Mirror = 1,
+
+    /// Clamp the coordinate to the edge of the texture.
+    Clamp = 2,
+
+    /// Use the border color outside the texture.
+    Border = 3,
+}
+
+/// Texture filtering mode.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum TextureFilter {
+    /// Linear filtering.
+    Linear = 0,
+
+    /// Point (nearest) filtering.
+    Point = 1,
+
+    /// Anisotropic filtering. Not available for mip filtering.
+    Anisotropic = 2,
+}
+
+/// Texture compare function, used when sampling depth textures.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum TextureCompare {
+    /// Passes if the reference value is less than the sampled value.
+    Less = 1,
+
+    /// Passes if the reference value is less than or equal to the sampled value.
+    LessEqual = 2,
+
+    /// Passes if the reference value is equal to the sampled value.
+    Equal = 3,
+
+    /// Passes if the reference value is greater than or equal to the sampled value.
+    GreaterEqual = 4,
+
+    /// Passes if the reference value is greater than the sampled value.
+    Greater = 5,
+
+    /// Passes if the reference value is not equal to the sampled value.
+    NotEqual = 6,
+
+    /// Never passes.
+    Never = 7,
+
+    /// Always passes.
+    Always = 8,
+}
+
+/// Builds the flags for wrapping the U texture coordinate.
+#[inline]
+pub fn texture_wrap_u(wrap: TextureWrap) -> TextureFlags {
+    TextureFlags::from_bits((wrap as u32) << bgfx_sys::BGFX_TEXTURE_U_SHIFT).unwrap()
+}
+
+/// Builds the flags for wrapping the V texture coordinate.
+#[inline]
+pub fn texture_wrap_v(wrap: TextureWrap) -> TextureFlags {
+    TextureFlags::from_bits((wrap as u32) << bgfx_sys::BGFX_TEXTURE_V_SHIFT).unwrap()
+}
+
+/// Builds the flags for wrapping the W texture coordinate.
+#[inline]
+pub fn texture_wrap_w(wrap: TextureWrap) -> TextureFlags {
+    TextureFlags::from_bits((wrap as u32) << bgfx_sys::BGFX_TEXTURE_W_SHIFT).unwrap()
+}
+
+/// Builds the flags for wrapping all of the U, V and W texture coordinates the same way.
+#[inline]
+pub fn texture_wrap(wrap: TextureWrap) -> TextureFlags {
+    texture_wrap_u(wrap) | texture_wrap_v(wrap) | texture_wrap_w(wrap)
+}
+
+/// Builds the flags for minification filtering.
+#[inline]
+pub fn texture_min_filter(filter: TextureFilter) -> TextureFlags {
+    TextureFlags::from_bits((filter as u32) << bgfx_sys::BGFX_TEXTURE_MIN_SHIFT).unwrap()
+}
+
+/// Builds the flags for magnification filtering.
+#[inline]
+pub fn texture_mag_filter(filter: TextureFilter) -> TextureFlags {
+    TextureFlags::from_bits((filter as u32) << bgfx_sys::BGFX_TEXTURE_MAG_SHIFT).unwrap()
+}
+
+/// Builds the flags for filtering between mip levels.
+///
+/// # Panics
+///
+/// Panics if `filter` is `TextureFilter::Anisotropic`, which bgfx does not support for mips.
+#[inline]
+pub fn texture_mip_filter(filter: TextureFilter) -> TextureFlags {
+    assert!(filter != TextureFilter::Anisotropic,
+            "Anisotropic filtering is not supported between mip levels");
+    TextureFlags::from_bits((filter as u32) << bgfx_sys::BGFX_TEXTURE_MIP_SHIFT).unwrap()
+}
+
+/// Builds the flags for using the same filtering for minification, magnification and mips.
+/// Anisotropic filtering falls back to linear filtering between mip levels.
+#[inline]
+pub fn texture_filter(filter: TextureFilter) -> TextureFlags {
+    let mip = if filter == TextureFilter::Anisotropic { TextureFilter::Linear } else { filter };
+    texture_min_filter(filter) | texture_mag_filter(filter) | texture_mip_filter(mip)
+}
+
+/// Builds the flags for the compare function used when sampling the texture.
+#[inline]
+pub fn texture_compare(compare: TextureCompare) -> TextureFlags {
+    TextureFlags::from_bits((compare as u32) << bgfx_sys::BGFX_TEXTURE_COMPARE_SHIFT).unwrap()
+}
+
+/// Builds the flags for using the palette color at `index` as the border color.
+///
+/// # Panics
+///
+/// Panics if `index` is not below 16, the size of the palette.
+#[inline]
+pub fn texture_border_color(index: u8) -> TextureFlags {
+    assert!(index < 16, "Palette index {} is out of range", index);
+    TextureFlags::from_bits(BGFX_TEXTURE_BORDER_COLOR!(index)).unwrap()
+}
+
+/// Stencil test function.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum StencilTest {
+    /// Passes if the reference value is less than the stencil value.
+    Less = 1,
+
+    /// Passes if the reference value is less than or equal to the stencil value.
+    LessEqual = 2,
+
+    /// Passes if the reference value is equal to the stencil value.
+    Equal = 3,
+
+    /// Passes if the reference value is greater than or equal to the stencil value.
+    GreaterEqual = 4,
+
+    /// Passes if the reference value is greater than the stencil value.
+    Greater = 5,
+
+    /// Passes if the reference value is not equal to the stencil value.
+    NotEqual = 6,
+
+    /// Never passes.
+    Never = 7,
+
+    /// Always passes.
+    Always = 8,
+}
+
+/// Operation applied to the stencil value.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum StencilOp {
+    /// Sets the value to zero.
+    Zero = 0,
+
+    /// Keeps the current value.
+    Keep = 1,
+
+    /// Replaces the value with the reference value.
+    Replace = 2,
+
+    /// Increments the value, wrapping around on overflow.
+    Incr = 3,
+
+    /// Increments the value, saturating at the maximum value.
+    IncrSat = 4,
+
+    /// Decrements the value, wrapping around on underflow.
+    Decr = 5,
+
+    /// Decrements the value, saturating at zero.
+    DecrSat = 6,
+
+    /// Inverts the bits of the value.
+    Invert = 7,
+}
+
+/// Stencil state of a single face, used with `Bgfx::set_stencil`.
+///
+/// # Example
+///
+/// ```
+/// use bgfx::{StencilOp, StencilState, StencilTest};
+///
+/// let state = StencilState::new(StencilTest::Always)
+///     .reference(1)
+///     .pass_op(StencilOp::Replace);
+/// ```
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct StencilState {
+    bits: u32,
+}
+
+impl StencilState {
+
+    /// Creates a new stencil state using the given test, with a reference value of `0`, a read
+    /// mask of `0xff`, and `StencilOp::Keep` for all operations.
+    #[inline]
+    pub fn new(test: StencilTest) -> StencilState {
+        StencilState { bits: 0 }
+            .test(test)
+            .read_mask(0xff)
+            .fail_stencil_op(StencilOp::Keep)
+            .fail_depth_op(StencilOp::Keep)
+            .pass_op(StencilOp::Keep)
+    }
+
+    /// Sets the stencil test function.
+    #[inline]
+    pub fn test(self, test: StencilTest) -> StencilState {
+        self.with(bgfx_sys::BGFX_STENCIL_TEST_MASK,
+                  (test as u32) << bgfx_sys::BGFX_STENCIL_TEST_SHIFT)
+    }
+
+    /// Sets the reference value used by the stencil test and `StencilOp::Replace`.
+    #[inline]
+    pub fn reference(self, value: u8) -> StencilState {
+        self.with(bgfx_sys::BGFX_STENCIL_FUNC_REF_MASK, BGFX_STENCIL_FUNC_REF!(value))
+    }
+
+    /// Sets the mask applied to both the reference value and the stencil value when testing.
+    #[inline]
+    pub fn read_mask(self, mask: u8) -> StencilState {
+        self.with(bgfx_sys::BGFX_STENCIL_FUNC_RMASK_MASK, BGFX_STENCIL_FUNC_RMASK!(mask))
+    }
+
+    /// Sets the operation to apply when the stencil test fails.
+    #[inline]
+    pub fn fail_stencil_op(self, op: StencilOp) -> StencilState {
+        self.with(bgfx_sys::BGFX_STENCIL_OP_FAIL_S_MASK,
+                  (op as u32) << bgfx_sys::BGFX_STENCIL_OP_FAIL_S_SHIFT)
+    }
+
+    /// Sets the operation to apply when the stencil test passes, but the depth test fails.
+    #[inline]
+    pub fn fail_depth_op(self, op: StencilOp) -> StencilState {
+        self.with(bgfx_sys::BGFX_STENCIL_OP_FAIL_Z_MASK,
+                  (op as u32) << bgfx_sys::BGFX_STENCIL_OP_FAIL_Z_SHIFT)
+    }
+
+    /// Sets the operation to apply when both the stencil test and the depth test pass.
+    #[inline]
+    pub fn pass_op(self, op: StencilOp) -> StencilState {
+        self.with(bgfx_sys::BGFX_STENCIL_OP_PASS_Z_MASK,
+                  (op as u32) << bgfx_sys::BGFX_STENCIL_OP_PASS_Z_SHIFT)
+    }
+
+    /// Gets the raw bgfx stencil bits of the state.
+    #[inline]
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+
+    #[inline]
+    fn with(self, mask: u32, value: u32) -> StencilState {
+        StencilState { bits: (self.bits & !mask) | (value & mask) }
+    }
+
+}
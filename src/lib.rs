@@ -31,6 +31,8 @@
 //! Once the platform data has been initialized, a new thread should be spawned to act as the main
 //! thread. This thread should call [`bgfx::init`] to initialize bgfx. The object returned by that
 //! function should be used to access bgfx API calls.
+//! Since bgfx resources borrow that object, which is not `Sync`, they are not `Send`, and must be
+//! created and used on the main thread.
 //!
 //! ```no_run
 //! std::thread::spawn(|| {
@@ -64,10 +66,17 @@ extern crate bgfx_sys;
 extern crate bitflags;
 extern crate libc;
 
+use std::cell::{Cell, RefCell};
 use std::ffi;
+use std::fmt;
+use std::future::Future;
 use std::marker::PhantomData;
 use std::mem;
+use std::pin::Pin;
 use std::ptr;
+use std::str;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
 
 pub mod flags;
 
@@ -123,6 +132,55 @@ pub enum RendererType {
     Default = bgfx_sys::bgfx_renderer_type_BGFX_RENDERER_TYPE_COUNT as u32,
 }
 
+impl RendererType {
+
+    const ALL: [RendererType; 10] = [RendererType::Noop,
+                                     RendererType::Direct3D9,
+                                     RendererType::Direct3D11,
+                                     RendererType::Direct3D12,
+                                     RendererType::GNM,
+                                     RendererType::Metal,
+                                     RendererType::OpenGLES,
+                                     RendererType::OpenGL,
+                                     RendererType::Vulkan,
+                                     RendererType::Default];
+
+}
+
+impl fmt::Display for RendererType {
+
+    /// Formats the renderer type using the name bgfx gives it, such as "Direct3D 11".
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if *self == RendererType::Default {
+            return f.write_str("Default");
+        }
+
+        let name = unsafe {
+            ffi::CStr::from_ptr(bgfx_sys::bgfx_get_renderer_name(*self as u32 as i32))
+        };
+        f.write_str(&name.to_string_lossy())
+    }
+
+}
+
+impl str::FromStr for RendererType {
+    type Err = BgfxError;
+
+    /// Parses a renderer type from either the name bgfx gives it, such as "Direct3D 11", or the
+    /// name of the variant, such as "Direct3D11". Case is ignored.
+    fn from_str(s: &str) -> Result<RendererType, BgfxError> {
+        RendererType::ALL
+            .iter()
+            .cloned()
+            .find(|renderer| {
+                s.eq_ignore_ascii_case(&renderer.to_string()) ||
+                s.eq_ignore_ascii_case(&format!("{:?}", renderer))
+            })
+            .ok_or(BgfxError::UnknownRenderer)
+    }
+
+}
+
 /// `render_frame()` results.
 #[repr(u32)]
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
@@ -233,6 +291,403 @@ pub enum AttribType {
     Float,
 }
 
+/// Texture format.
+#[repr(u32)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum TextureFormat {
+    /// DXT1 block compression.
+    BC1 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_BC1 as u32,
+
+    /// DXT3 block compression.
+    BC2 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_BC2 as u32,
+
+    /// DXT5 block compression.
+    BC3 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_BC3 as u32,
+
+    /// LATC1/ATI1 block compression.
+    BC4 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_BC4 as u32,
+
+    /// LATC2/ATI2 block compression.
+    BC5 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_BC5 as u32,
+
+    /// BC6H block compression.
+    BC6H = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_BC6H as u32,
+
+    /// BC7 block compression.
+    BC7 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_BC7 as u32,
+
+    /// ETC1 RGB8 block compression.
+    ETC1 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_ETC1 as u32,
+
+    /// ETC2 RGB8 block compression.
+    ETC2 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_ETC2 as u32,
+
+    /// ETC2 RGBA8 block compression.
+    ETC2A = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_ETC2A as u32,
+
+    /// ETC2 RGB8A1 block compression.
+    ETC2A1 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_ETC2A1 as u32,
+
+    /// PVRTC1 RGB 2BPP block compression.
+    PTC12 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_PTC12 as u32,
+
+    /// PVRTC1 RGB 4BPP block compression.
+    PTC14 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_PTC14 as u32,
+
+    /// PVRTC1 RGBA 2BPP block compression.
+    PTC12A = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_PTC12A as u32,
+
+    /// PVRTC1 RGBA 4BPP block compression.
+    PTC14A = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_PTC14A as u32,
+
+    /// PVRTC2 RGBA 2BPP block compression.
+    PTC22 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_PTC22 as u32,
+
+    /// PVRTC2 RGBA 4BPP block compression.
+    PTC24 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_PTC24 as u32,
+
+    /// Unknown compressed format. Marks the end of the compressed formats.
+    Unknown = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_UNKNOWN as u32,
+
+    /// 1-bit single channel.
+    R1 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R1 as u32,
+
+    /// 8-bit alpha channel, unsigned normalized.
+    A8 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_A8 as u32,
+
+    /// Red channel, 8-bit unsigned normalized.
+    R8 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R8 as u32,
+
+    /// Red channel, 8-bit signed integer.
+    R8I = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R8I as u32,
+
+    /// Red channel, 8-bit unsigned integer.
+    R8U = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R8U as u32,
+
+    /// Red channel, 8-bit signed normalized.
+    R8S = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R8S as u32,
+
+    /// Red channel, 16-bit unsigned normalized.
+    R16 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R16 as u32,
+
+    /// Red channel, 16-bit signed integer.
+    R16I = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R16I as u32,
+
+    /// Red channel, 16-bit unsigned integer.
+    R16U = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R16U as u32,
+
+    /// Red channel, 16-bit float.
+    R16F = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R16F as u32,
+
+    /// Red channel, 16-bit signed normalized.
+    R16S = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R16S as u32,
+
+    /// Red channel, 32-bit signed integer.
+    R32I = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R32I as u32,
+
+    /// Red channel, 32-bit unsigned integer.
+    R32U = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R32U as u32,
+
+    /// Red channel, 32-bit float.
+    R32F = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R32F as u32,
+
+    /// RG channels, 8-bit unsigned normalized.
+    RG8 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG8 as u32,
+
+    /// RG channels, 8-bit signed integer.
+    RG8I = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG8I as u32,
+
+    /// RG channels, 8-bit unsigned integer.
+    RG8U = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG8U as u32,
+
+    /// RG channels, 8-bit signed normalized.
+    RG8S = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG8S as u32,
+
+    /// RG channels, 16-bit unsigned normalized.
+    RG16 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG16 as u32,
+
+    /// RG channels, 16-bit signed integer.
+    RG16I = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG16I as u32,
+
+    /// RG channels, 16-bit unsigned integer.
+    RG16U = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG16U as u32,
+
+    /// RG channels, 16-bit float.
+    RG16F = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG16F as u32,
+
+    /// RG channels, 16-bit signed normalized.
+    RG16S = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG16S as u32,
+
+    /// RG channels, 32-bit signed integer.
+    RG32I = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG32I as u32,
+
+    /// RG channels, 32-bit unsigned integer.
+    RG32U = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG32U as u32,
+
+    /// RG channels, 32-bit float.
+    RG32F = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG32F as u32,
+
+    /// RGB channels, 8-bit unsigned normalized.
+    RGB8 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGB8 as u32,
+
+    /// RGB channels, 8-bit signed integer.
+    RGB8I = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGB8I as u32,
+
+    /// RGB channels, 8-bit unsigned integer.
+    RGB8U = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGB8U as u32,
+
+    /// RGB channels, 8-bit signed normalized.
+    RGB8S = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGB8S as u32,
+
+    /// RGB with 9-bit mantissas and a shared 5-bit exponent.
+    RGB9E5F = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGB9E5F as u32,
+
+    /// BGRA channels, 8-bit unsigned normalized.
+    BGRA8 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_BGRA8 as u32,
+
+    /// RGBA channels, 8-bit unsigned normalized.
+    RGBA8 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA8 as u32,
+
+    /// RGBA channels, 8-bit signed integer.
+    RGBA8I = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA8I as u32,
+
+    /// RGBA channels, 8-bit unsigned integer.
+    RGBA8U = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA8U as u32,
+
+    /// RGBA channels, 8-bit signed normalized.
+    RGBA8S = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA8S as u32,
+
+    /// RGBA channels, 16-bit unsigned normalized.
+    RGBA16 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA16 as u32,
+
+    /// RGBA channels, 16-bit signed integer.
+    RGBA16I = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA16I as u32,
+
+    /// RGBA channels, 16-bit unsigned integer.
+    RGBA16U = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA16U as u32,
+
+    /// RGBA channels, 16-bit float.
+    RGBA16F = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA16F as u32,
+
+    /// RGBA channels, 16-bit signed normalized.
+    RGBA16S = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA16S as u32,
+
+    /// RGBA channels, 32-bit signed integer.
+    RGBA32I = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA32I as u32,
+
+    /// RGBA channels, 32-bit unsigned integer.
+    RGBA32U = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA32U as u32,
+
+    /// RGBA channels, 32-bit float.
+    RGBA32F = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA32F as u32,
+
+    /// Packed 5-bit red, 6-bit green and 5-bit blue.
+    R5G6B5 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_R5G6B5 as u32,
+
+    /// Packed 4-bit RGBA.
+    RGBA4 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGBA4 as u32,
+
+    /// Packed 5-bit RGB and 1-bit alpha.
+    RGB5A1 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGB5A1 as u32,
+
+    /// Packed 10-bit RGB and 2-bit alpha.
+    RGB10A2 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RGB10A2 as u32,
+
+    /// Packed 11-bit float red and green, 10-bit float blue.
+    RG11B10F = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_RG11B10F as u32,
+
+    /// Unknown depth format. Marks the start of the depth formats.
+    UnknownDepth = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_UNKNOWN_DEPTH as u32,
+
+    /// 16-bit depth.
+    D16 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_D16 as u32,
+
+    /// 24-bit depth.
+    D24 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_D24 as u32,
+
+    /// 24-bit depth and 8-bit stencil.
+    D24S8 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_D24S8 as u32,
+
+    /// 32-bit depth.
+    D32 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_D32 as u32,
+
+    /// 16-bit float depth.
+    D16F = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_D16F as u32,
+
+    /// 24-bit float depth.
+    D24F = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_D24F as u32,
+
+    /// 32-bit float depth.
+    D32F = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_D32F as u32,
+
+    /// 8-bit stencil only.
+    D0S8 = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_D0S8 as u32,
+}
+
+impl TextureFormat {
+
+    /// Gets the number of bits per pixel of the format.
+    #[inline]
+    pub fn bits_per_pixel(self) -> u8 {
+        self.block_info().0
+    }
+
+    /// Gets the width of a block of the format, in pixels. This is `1` for most uncompressed
+    /// formats.
+    #[inline]
+    pub fn block_width(self) -> u8 {
+        self.block_info().1
+    }
+
+    /// Gets the height of a block of the format, in pixels. This is `1` for uncompressed formats.
+    #[inline]
+    pub fn block_height(self) -> u8 {
+        self.block_info().2
+    }
+
+    /// Checks whether the format is block compressed.
+    #[inline]
+    pub fn is_compressed(self) -> bool {
+        (self as u32) < (TextureFormat::Unknown as u32)
+    }
+
+    /// Checks whether the format is a depth format.
+    #[inline]
+    pub fn is_depth(self) -> bool {
+        (self as u32) > (TextureFormat::UnknownDepth as u32)
+    }
+
+    /// Checks whether the format can be sampled as sRGB, using `TEXTURE_SRGB`.
+    pub fn supports_srgb(self) -> bool {
+        use TextureFormat::*;
+        match self {
+            BC1 | BC2 | BC3 | BC7 | ETC2 | ETC2A | ETC2A1 | PTC12 | PTC14 | PTC12A | PTC14A |
+            RGB8 | BGRA8 | RGBA8 => true,
+            _ => false,
+        }
+    }
+
+    /// Gets the bits per pixel, block width, block height, and minimum number of blocks along the
+    /// X and Y axes of the format, matching bgfx's internal block info table.
+    fn block_info(self) -> (u8, u8, u8, u8, u8) {
+        use TextureFormat::*;
+        match self {
+            BC1 | BC4 | ETC1 | ETC2 | ETC2A1 => (4, 4, 4, 1, 1),
+            BC2 | BC3 | BC5 | BC6H | BC7 | ETC2A => (8, 4, 4, 1, 1),
+            PTC12 | PTC12A | PTC22 => (2, 8, 4, 2, 2),
+            PTC14 | PTC14A | PTC24 => (4, 4, 4, 2, 2),
+            Unknown | UnknownDepth => (0, 1, 1, 1, 1),
+            R1 => (1, 8, 1, 1, 1),
+            A8 | R8 | R8I | R8U | R8S | D0S8 => (8, 1, 1, 1, 1),
+            R16 | R16I | R16U | R16F | R16S => (16, 1, 1, 1, 1),
+            R32I | R32U | R32F => (32, 1, 1, 1, 1),
+            RG8 | RG8I | RG8U | RG8S => (16, 1, 1, 1, 1),
+            RG16 | RG16I | RG16U | RG16F | RG16S => (32, 1, 1, 1, 1),
+            RG32I | RG32U | RG32F => (64, 1, 1, 1, 1),
+            RGB8 | RGB8I | RGB8U | RGB8S => (24, 1, 1, 1, 1),
+            RGB9E5F | BGRA8 | RGBA8 | RGBA8I | RGBA8U | RGBA8S => (32, 1, 1, 1, 1),
+            RGBA16 | RGBA16I | RGBA16U | RGBA16F | RGBA16S => (64, 1, 1, 1, 1),
+            RGBA32I | RGBA32U | RGBA32F => (128, 1, 1, 1, 1),
+            R5G6B5 | RGBA4 | RGB5A1 => (16, 1, 1, 1, 1),
+            RGB10A2 | RG11B10F => (32, 1, 1, 1, 1),
+            D16 | D16F => (16, 1, 1, 1, 1),
+            D24 | D24S8 | D32 | D24F | D32F => (32, 1, 1, 1, 1),
+        }
+    }
+
+    /// Gets the number of bytes in one row of blocks, and the number of block rows, of a region
+    /// with the given dimensions.
+    fn region_layout(self, width: u16, height: u16) -> (u32, u32) {
+        let bpp = self.bits_per_pixel() as u64;
+        let block_width = self.block_width() as u64;
+        let block_height = self.block_height() as u64;
+        let blocks_x = (width as u64 + block_width - 1) / block_width;
+        let blocks_y = (height as u64 + block_height - 1) / block_height;
+        let row_bytes = (blocks_x * block_width * block_height * bpp + 7) / 8;
+        assert!(row_bytes <= std::u32::MAX as u64, "Texture row size does not fit in 32 bits");
+        (row_bytes as u32, blocks_y as u32)
+    }
+
+}
+
+/// Uniform type.
+#[repr(u32)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum UniformType {
+    /// Integer. Used for texture samplers.
+    Int1 = bgfx_sys::bgfx_uniform_type::BGFX_UNIFORM_TYPE_INT1 as u32,
+
+    /// 4-component vector.
+    Vec4 = bgfx_sys::bgfx_uniform_type::BGFX_UNIFORM_TYPE_VEC4 as u32,
+
+    /// 3x3 matrix.
+    Mat3 = bgfx_sys::bgfx_uniform_type::BGFX_UNIFORM_TYPE_MAT3 as u32,
+
+    /// 4x4 matrix.
+    Mat4 = bgfx_sys::bgfx_uniform_type::BGFX_UNIFORM_TYPE_MAT4 as u32,
+}
+
+/// Side of a cube map.
+#[repr(u8)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum CubeMapSide {
+    /// Positive X.
+    PositiveX = bgfx_sys::BGFX_CUBE_MAP_POSITIVE_X,
+
+    /// Negative X.
+    NegativeX = bgfx_sys::BGFX_CUBE_MAP_NEGATIVE_X,
+
+    /// Positive Y.
+    PositiveY = bgfx_sys::BGFX_CUBE_MAP_POSITIVE_Y,
+
+    /// Negative Y.
+    NegativeY = bgfx_sys::BGFX_CUBE_MAP_NEGATIVE_Y,
+
+    /// Positive Z.
+    PositiveZ = bgfx_sys::BGFX_CUBE_MAP_POSITIVE_Z,
+
+    /// Negative Z.
+    NegativeZ = bgfx_sys::BGFX_CUBE_MAP_NEGATIVE_Z,
+}
+
+/// Compute shader access to a buffer or image.
+#[repr(u32)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum Access {
+    /// Read only.
+    Read = bgfx_sys::bgfx_access::BGFX_ACCESS_READ as u32,
+
+    /// Write only.
+    Write = bgfx_sys::bgfx_access::BGFX_ACCESS_WRITE as u32,
+
+    /// Read and write.
+    ReadWrite = bgfx_sys::bgfx_access::BGFX_ACCESS_READWRITE as u32,
+}
+
+/// Back buffer ratio, used for textures and frame buffers that are sized relative to the back
+/// buffer.
+#[repr(u32)]
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum BackbufferRatio {
+    /// Equal to the back buffer.
+    Equal = bgfx_sys::bgfx_backbuffer_ratio::BGFX_BACKBUFFER_RATIO_EQUAL as u32,
+
+    /// Half of the back buffer.
+    Half = bgfx_sys::bgfx_backbuffer_ratio::BGFX_BACKBUFFER_RATIO_HALF as u32,
+
+    /// Quarter of the back buffer.
+    Quarter = bgfx_sys::bgfx_backbuffer_ratio::BGFX_BACKBUFFER_RATIO_QUARTER as u32,
+
+    /// Eighth of the back buffer.
+    Eighth = bgfx_sys::bgfx_backbuffer_ratio::BGFX_BACKBUFFER_RATIO_EIGHTH as u32,
+
+    /// Sixteenth of the back buffer.
+    Sixteenth = bgfx_sys::bgfx_backbuffer_ratio::BGFX_BACKBUFFER_RATIO_SIXTEENTH as u32,
+
+    /// Double the back buffer.
+    Double = bgfx_sys::bgfx_backbuffer_ratio::BGFX_BACKBUFFER_RATIO_DOUBLE as u32,
+}
+
 /// bgfx error.
 #[derive(Debug)]
 pub enum BgfxError {
@@ -244,6 +699,67 @@ pub enum BgfxError {
 
     /// Initialization failed.
     InitFailed,
+
+    /// A renderer name could not be parsed.
+    UnknownRenderer,
+}
+
+/// Rectangular region of a 2D texture or cube map side.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Default)]
+pub struct Rect {
+    /// Left edge of the region.
+    pub x: u16,
+
+    /// Top edge of the region.
+    pub y: u16,
+
+    /// Width of the region.
+    pub width: u16,
+
+    /// Height of the region.
+    pub height: u16,
+}
+
+impl Rect {
+
+    /// Creates a new rectangle.
+    #[inline]
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Rect {
+        Rect { x: x, y: y, width: width, height: height }
+    }
+
+}
+
+/// Box-shaped region of a 3D texture.
+#[derive(PartialEq, Eq, Debug, Copy, Clone, Default)]
+pub struct Box3 {
+    /// Left edge of the region.
+    pub x: u16,
+
+    /// Top edge of the region.
+    pub y: u16,
+
+    /// Front edge of the region.
+    pub z: u16,
+
+    /// Width of the region.
+    pub width: u16,
+
+    /// Height of the region.
+    pub height: u16,
+
+    /// Depth of the region.
+    pub depth: u16,
+}
+
+impl Box3 {
+
+    /// Creates a new box.
+    #[inline]
+    pub fn new(x: u16, y: u16, z: u16, width: u16, height: u16, depth: u16) -> Box3 {
+        Box3 { x: x, y: y, z: z, width: width, height: height, depth: depth }
+    }
+
 }
 
 /// bgfx-managed buffer of memory.
@@ -290,15 +806,81 @@ impl<'b> Memory<'b> {
         }
     }
 
+    /// Gets the raw handle of an optional memory buffer, for calls where the memory is optional.
+    #[inline]
+    fn handle_or_null(mem: Option<Memory<'b>>) -> *const bgfx_sys::bgfx_memory_t {
+        mem.map_or(ptr::null(), |mem| mem.handle)
+    }
+
+    /// Gets the size of the buffer, in bytes.
+    #[inline]
+    fn size(&self) -> u32 {
+        unsafe { (*self.handle).size }
+    }
+
+}
+
+/// Result of an occlusion query.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum OcclusionQueryResult {
+    /// The query geometry was not visible.
+    Invisible,
+
+    /// The query geometry was visible. The parameter is the number of visible pixels.
+    Visible(i32),
+
+    /// The query has no result yet.
+    NoResult,
+}
+
+/// Occlusion query, used for checking whether geometry was visible in a previous frame.
+pub struct OcclusionQuery<'b> {
+    handle: bgfx_sys::bgfx_occlusion_query_handle_t,
+    _phantom: PhantomData<&'b Bgfx>,
+}
+
+impl<'b> OcclusionQuery<'b> {
+
+    /// Creates a new occlusion query.
+    #[inline]
+    pub fn new(_bgfx: &'b Bgfx) -> OcclusionQuery<'b> {
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_occlusion_query();
+            OcclusionQuery { handle: handle, _phantom: PhantomData }
+        }
+    }
+
+    /// Gets the result of the query.
+    pub fn get_result(&self) -> OcclusionQueryResult {
+        use bgfx_sys::bgfx_occlusion_query_result::*;
+
+        let mut num_pixels = 0;
+        let result = unsafe { bgfx_sys::bgfx_get_result(self.handle, &mut num_pixels) };
+        match result {
+            BGFX_OCCLUSION_QUERY_RESULT_INVISIBLE => OcclusionQueryResult::Invisible,
+            BGFX_OCCLUSION_QUERY_RESULT_VISIBLE => OcclusionQueryResult::Visible(num_pixels),
+            _ => OcclusionQueryResult::NoResult,
+        }
+    }
+
+}
+
+impl<'b> Drop for OcclusionQuery<'b> {
+
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { bgfx_sys::bgfx_destroy_occlusion_query(self.handle) }
+    }
+
 }
 
 /// Shader program.
 ///
-/// The program holds a vertex shader and a fragment shader.
+/// The program holds either a vertex shader and a fragment shader, or a single compute shader.
 pub struct Program<'s> {
     handle: bgfx_sys::bgfx_program_handle_t,
-    _vsh: Shader<'s>,
-    _fsh: Shader<'s>,
+    compute: bool,
+    _shaders: Vec<Shader<'s>>,
 }
 
 impl<'s> Program<'s> {
@@ -309,10 +891,26 @@ impl<'s> Program<'s> {
     pub fn new(vsh: Shader<'s>, fsh: Shader<'s>) -> Program<'s> {
         unsafe {
             let handle = bgfx_sys::bgfx_create_program(vsh.handle, fsh.handle, false);
-            Program { handle: handle, _vsh: vsh, _fsh: fsh }
+            Program { handle: handle, compute: false, _shaders: vec![vsh, fsh] }
         }
     }
 
+    /// Creates a new compute program from a compute shader. Ownership of the shader is moved to
+    /// the program.
+    #[inline]
+    pub fn compute(csh: Shader<'s>) -> Program<'s> {
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_compute_program(csh.handle, false);
+            Program { handle: handle, compute: true, _shaders: vec![csh] }
+        }
+    }
+
+    /// Checks whether this is a compute program.
+    #[inline]
+    pub fn is_compute(&self) -> bool {
+        self.compute
+    }
+
 }
 
 impl<'s> Drop for Program<'s> {
@@ -352,63 +950,1708 @@ impl<'m> Drop for Shader<'m> {
 
 }
 
-/// Vertex index buffer.
-pub struct IndexBuffer<'m> {
-    handle: bgfx_sys::bgfx_index_buffer_handle_t,
-    _phantom: PhantomData<&'m ()>,
+/// Texture.
+///
+/// Can be a 2D texture (optionally with multiple layers), a 3D texture, or a cube map. The
+/// texture is destroyed when dropped.
+pub struct Texture<'m> {
+    handle: bgfx_sys::bgfx_texture_handle_t,
+    width: u16,
+    height: u16,
+    depth: u16,
+    num_layers: u16,
+    num_mips: u8,
+    format: TextureFormat,
+    kind: TextureKind,
+    flags: TextureFlags,
+    _phantom: PhantomData<&'m Bgfx>,
 }
 
-impl<'m> IndexBuffer<'m> {
+/// The kind of a texture, which determines how it can be updated.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+enum TextureKind {
+    Texture2D,
+    Texture3D,
+    Cube,
+
+    /// Attachment of a frame buffer sized relative to the back buffer, whose actual size is
+    /// unknown.
+    BackbufferScaled,
+}
 
-    /// Creates a new index buffer from bgfx-managed memory.
+impl<'m> Texture<'m> {
+
+    /// Creates a new 2D texture.
+    ///
+    /// If `mem` is `None`, the texture contents are left uninitialized. `num_layers` is the number
+    /// of texture array layers, and should be `1` for regular textures.
     #[inline]
-    pub fn new(indices: Memory<'m>, flags: BufferFlags) -> IndexBuffer<'m> {
+    pub fn new_2d(_bgfx: &'m Bgfx,
+                  width: u16,
+                  height: u16,
+                  has_mips: bool,
+                  num_layers: u16,
+                  format: TextureFormat,
+                  flags: TextureFlags,
+                  mem: Option<Memory<'m>>)
+                  -> Texture<'m> {
         unsafe {
-            let handle = bgfx_sys::bgfx_create_index_buffer(indices.handle, flags.bits());
-            IndexBuffer { handle: handle, _phantom: PhantomData }
+            let handle = bgfx_sys::bgfx_create_texture_2d(width,
+                                                          height,
+                                                          has_mips,
+                                                          num_layers,
+                                                          mem::transmute(format),
+                                                          flags.bits(),
+                                                          Memory::handle_or_null(mem));
+            Texture {
+                handle: handle,
+                width: width,
+                height: height,
+                depth: 1,
+                num_layers: num_layers,
+                num_mips: calc_num_mips(has_mips, width, height, 1),
+                format: format,
+                kind: TextureKind::Texture2D,
+                flags: flags,
+                _phantom: PhantomData,
+            }
         }
     }
 
-}
-
-impl<'m> Drop for IndexBuffer<'m> {
+    /// Creates a new 3D texture.
+    ///
+    /// If `mem` is `None`, the texture contents are left uninitialized.
+    #[inline]
+    pub fn new_3d(_bgfx: &'m Bgfx,
+                  width: u16,
+                  height: u16,
+                  depth: u16,
+                  has_mips: bool,
+                  format: TextureFormat,
+                  flags: TextureFlags,
+                  mem: Option<Memory<'m>>)
+                  -> Texture<'m> {
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_texture_3d(width,
+                                                          height,
+                                                          depth,
+                                                          has_mips,
+                                                          mem::transmute(format),
+                                                          flags.bits(),
+                                                          Memory::handle_or_null(mem));
+            Texture {
+                handle: handle,
+                width: width,
+                height: height,
+                depth: depth,
+                num_layers: 1,
+                num_mips: calc_num_mips(has_mips, width, height, depth),
+                format: format,
+                kind: TextureKind::Texture3D,
+                flags: flags,
+                _phantom: PhantomData,
+            }
+        }
+    }
 
+    /// Creates a new cube map texture, where each side is `size` by `size` pixels.
+    ///
+    /// If `mem` is `None`, the texture contents are left uninitialized. `num_layers` is the number
+    /// of cube map array layers, and should be `1` for regular cube maps.
     #[inline]
-    fn drop(&mut self) {
-        unsafe { bgfx_sys::bgfx_destroy_index_buffer(self.handle) }
+    pub fn new_cube(_bgfx: &'m Bgfx,
+                    size: u16,
+                    has_mips: bool,
+                    num_layers: u16,
+                    format: TextureFormat,
+                    flags: TextureFlags,
+                    mem: Option<Memory<'m>>)
+                    -> Texture<'m> {
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_texture_cube(size,
+                                                            has_mips,
+                                                            num_layers,
+                                                            mem::transmute(format),
+                                                            flags.bits(),
+                                                            Memory::handle_or_null(mem));
+            Texture {
+                handle: handle,
+                width: size,
+                height: size,
+                depth: 1,
+                num_layers: num_layers,
+                num_mips: calc_num_mips(has_mips, size, size, 1),
+                format: format,
+                kind: TextureKind::Cube,
+                flags: flags,
+                _phantom: PhantomData,
+            }
+        }
     }
 
-}
+    /// Creates a new texture from the contents of a DDS, KTX or PVR container.
+    ///
+    /// `skip_mips` is the number of top level mips to skip when loading the texture. Returns the
+    /// texture along with information about it, or `None` if the container could not be parsed.
+    pub fn from_container(_bgfx: &'m Bgfx,
+                          mem: Memory<'m>,
+                          flags: TextureFlags,
+                          skip_mips: u8)
+                          -> Option<(Texture<'m>, TextureInfo)> {
+        unsafe {
+            let mut info: bgfx_sys::bgfx_texture_info_t = mem::zeroed();
+            let handle = bgfx_sys::bgfx_create_texture(mem.handle,
+                                                       flags.bits(),
+                                                       skip_mips,
+                                                       &mut info);
+            if handle.idx == std::u16::MAX {
+                return None;
+            }
 
-/// Vertex data buffer.
-pub struct VertexBuffer<'m> {
-    handle: bgfx_sys::bgfx_vertex_buffer_handle_t,
-    _phantom: PhantomData<&'m Bgfx>,
-}
+            // bgfx still allocates a handle when the container can't be parsed, and only reports
+            // the failure through an unknown format.
+            let info = TextureInfo::from_raw(&info);
+            if info.format == TextureFormat::Unknown {
+                bgfx_sys::bgfx_destroy_texture(handle);
+                return None;
+            }
+
+            let kind = if info.cube_map {
+                TextureKind::Cube
+            } else if info.depth > 1 {
+                TextureKind::Texture3D
+            } else {
+                TextureKind::Texture2D
+            };
+            let texture = Texture {
+                handle: handle,
+                width: info.width,
+                height: info.height,
+                depth: info.depth,
+                num_layers: info.num_layers,
+                num_mips: info.num_mips,
+                format: info.format,
+                kind: kind,
+                flags: flags,
+                _phantom: PhantomData,
+            };
+
+            Some((texture, info))
+        }
+    }
+
+    /// Gets the width of the texture, in pixels.
+    #[inline]
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Gets the height of the texture, in pixels.
+    #[inline]
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Gets the depth of the texture, in pixels. This is `1` for anything but 3D textures.
+    #[inline]
+    pub fn depth(&self) -> u16 {
+        self.depth
+    }
+
+    /// Gets the number of array layers in the texture.
+    #[inline]
+    pub fn num_layers(&self) -> u16 {
+        self.num_layers
+    }
+
+    /// Gets the number of mip levels in the texture, including the base level.
+    #[inline]
+    pub fn num_mips(&self) -> u8 {
+        self.num_mips
+    }
+
+    /// Gets the format of the texture.
+    #[inline]
+    pub fn format(&self) -> TextureFormat {
+        self.format
+    }
+
+    /// Checks whether the texture is a cube map.
+    #[inline]
+    pub fn is_cube_map(&self) -> bool {
+        self.kind == TextureKind::Cube
+    }
+
+    /// Reads back the contents of a mip level of the texture.
+    ///
+    /// The read is performed asynchronously by the render thread, and the returned [`Readback`]
+    /// will only hand out the data once the frame it completes on has been reached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the texture was not created with `TEXTURE_READ_BACK`, if it is the attachment of
+    /// a frame buffer sized relative to the back buffer, or if `mip` is out of range.
+    ///
+    /// [`Readback`]: struct.Readback.html
+    pub fn read<'b>(&self, bgfx: &'b Bgfx, mip: u8) -> Readback<'b> {
+        assert!(self.kind != TextureKind::BackbufferScaled,
+                "Texture size depends on the back buffer");
+        assert!(self.flags.contains(TEXTURE_READ_BACK),
+                "Texture was not created with TEXTURE_READ_BACK");
+        assert!(mip < self.num_mips, "Mip level out of range");
+
+        let width = std::cmp::max(1, self.width >> mip);
+        let height = std::cmp::max(1, self.height >> mip);
+        let (row_bytes, rows) = self.format.region_layout(width, height);
+        let mut data = vec![0u8; row_bytes as usize * rows as usize];
+
+        let frame = unsafe {
+            bgfx_sys::bgfx_read_texture(self.handle,
+                                        data.as_mut_ptr() as *mut std::os::raw::c_void,
+                                        mip)
+        };
+
+        Readback {
+            bgfx: bgfx,
+            data: Some(data),
+            frame: frame,
+        }
+    }
+
+    /// Updates a region of a 2D texture.
+    ///
+    /// `pitch` is the number of bytes between rows of blocks in `mem`. If `None`, rows are assumed
+    /// to be tightly packed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the texture is not a 2D texture, if `layer` or `mip` is out of range, if `rect`
+    /// does not fit within the mip level, or if `mem` is too small to hold the region.
+    pub fn update_2d(&self,
+                     layer: u16,
+                     mip: u8,
+                     rect: Rect,
+                     mem: Memory,
+                     pitch: Option<u16>) {
+        assert!(self.kind == TextureKind::Texture2D, "Texture is not a 2D texture");
+        assert!(layer < self.num_layers, "Layer out of range");
+        let region = Box3::new(rect.x, rect.y, 0, rect.width, rect.height, 1);
+        self.check_region(mip, region, &mem, pitch);
+
+        unsafe {
+            bgfx_sys::bgfx_update_texture_2d(self.handle,
+                                             layer,
+                                             mip,
+                                             rect.x,
+                                             rect.y,
+                                             rect.width,
+                                             rect.height,
+                                             mem.handle,
+                                             pitch.unwrap_or(std::u16::MAX));
+        }
+    }
+
+    /// Updates a region of a 3D texture.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the texture is not a 3D texture, if `mip` is out of range, if `region` does not
+    /// fit within the mip level, or if `mem` is too small to hold the region.
+    pub fn update_3d(&self, mip: u8, region: Box3, mem: Memory) {
+        assert!(self.kind == TextureKind::Texture3D, "Texture is not a 3D texture");
+        self.check_region(mip, region, &mem, None);
+
+        unsafe {
+            bgfx_sys::bgfx_update_texture_3d(self.handle,
+                                             mip,
+                                             region.x,
+                                             region.y,
+                                             region.z,
+                                             region.width,
+                                             region.height,
+                                             region.depth,
+                                             mem.handle);
+        }
+    }
+
+    /// Updates a region of one side of a cube map.
+    ///
+    /// `pitch` is the number of bytes between rows of blocks in `mem`. If `None`, rows are assumed
+    /// to be tightly packed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the texture is not a cube map, if `layer` or `mip` is out of range, if `rect` does
+    /// not fit within the mip level, or if `mem` is too small to hold the region.
+    pub fn update_cube(&self,
+                       layer: u16,
+                       side: CubeMapSide,
+                       mip: u8,
+                       rect: Rect,
+                       mem: Memory,
+                       pitch: Option<u16>) {
+        assert!(self.kind == TextureKind::Cube, "Texture is not a cube map");
+        assert!(layer < self.num_layers, "Layer out of range");
+        let region = Box3::new(rect.x, rect.y, 0, rect.width, rect.height, 1);
+        self.check_region(mip, region, &mem, pitch);
+
+        unsafe {
+            bgfx_sys::bgfx_update_texture_cube(self.handle,
+                                               layer,
+                                               side as u8,
+                                               mip,
+                                               rect.x,
+                                               rect.y,
+                                               rect.width,
+                                               rect.height,
+                                               mem.handle,
+                                               pitch.unwrap_or(std::u16::MAX));
+        }
+    }
+
+    /// Validates an update region against the dimensions of a mip level, and the size of the
+    /// memory holding the new contents.
+    fn check_region(&self, mip: u8, region: Box3, mem: &Memory, pitch: Option<u16>) {
+        assert!(mip < self.num_mips, "Mip level out of range");
+
+        let mip_size = |size: u16| std::cmp::max(1, size >> mip) as u32;
+        assert!(region.x as u32 + region.width as u32 <= mip_size(self.width) &&
+                region.y as u32 + region.height as u32 <= mip_size(self.height) &&
+                region.z as u32 + region.depth as u32 <= mip_size(self.depth),
+                "Region does not fit within the mip level");
+
+        let (row_bytes, rows) = self.format.region_layout(region.width, region.height);
+        let pitch = match pitch {
+            Some(pitch) => {
+                assert!(pitch as u32 >= row_bytes, "Pitch is smaller than a row of the region");
+                pitch as u32
+            }
+            None => row_bytes,
+        };
+        assert!(mem.size() as u64 >= pitch as u64 * rows as u64 * region.depth as u64,
+                "Memory is too small for the region");
+    }
+
+}
+
+impl<'m> Drop for Texture<'m> {
+
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { bgfx_sys::bgfx_destroy_texture(self.handle) }
+    }
+
+}
+
+/// Texture information, as reported by bgfx when creating a texture from a container.
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct TextureInfo {
+    /// Texture format.
+    pub format: TextureFormat,
+
+    /// Total amount of bytes required to store the texture.
+    pub storage_size: u32,
+
+    /// Texture width, in pixels.
+    pub width: u16,
+
+    /// Texture height, in pixels.
+    pub height: u16,
+
+    /// Texture depth, in pixels.
+    pub depth: u16,
+
+    /// Number of array layers.
+    pub num_layers: u16,
+
+    /// Number of mip levels, including the base level.
+    pub num_mips: u8,
+
+    /// Format bits per pixel.
+    pub bits_per_pixel: u8,
+
+    /// Whether the texture is a cube map.
+    pub cube_map: bool,
+}
+
+impl TextureInfo {
+
+    /// Calculates the layout and storage size of a texture with the given properties, the same way
+    /// `bgfx_calc_texture_size` does.
+    ///
+    /// Dimensions are rounded up to whole blocks of the format. This does not require bgfx to be
+    /// initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rounded up width or height does not fit in a `u16`, or if the storage size
+    /// does not fit in a `u32`.
+    pub fn calc(format: TextureFormat,
+                width: u16,
+                height: u16,
+                depth: u16,
+                cube_map: bool,
+                has_mips: bool,
+                num_layers: u16)
+                -> TextureInfo {
+        let (bpp, block_width, block_height, min_block_x, min_block_y) = format.block_info();
+        let bpp = bpp as u32;
+        let block_width = block_width as u32;
+        let block_height = block_height as u32;
+        let round_width = |w: u32| {
+            std::cmp::max(block_width * min_block_x as u32,
+                          (w + block_width - 1) / block_width * block_width)
+        };
+        let round_height = |h: u32| {
+            std::cmp::max(block_height * min_block_y as u32,
+                          (h + block_height - 1) / block_height * block_height)
+        };
+
+        let to_u16 = |size: u32| {
+            assert!(size <= std::u16::MAX as u32, "Rounded texture size does not fit in 16 bits");
+            size as u16
+        };
+
+        let width = to_u16(round_width(width as u32));
+        let height = to_u16(round_height(height as u32));
+        let depth = std::cmp::max(1, depth);
+        let num_layers = std::cmp::max(1, num_layers);
+        let num_mips = calc_num_mips(has_mips, width, height, depth);
+        let sides = if cube_map { 6 } else { 1 };
+
+        let (mut w, mut h, mut d) = (width as u32, height as u32, depth as u32);
+        let mut size = 0;
+        for _ in 0..num_mips {
+            w = round_width(w);
+            h = round_height(h);
+            d = std::cmp::max(1, d);
+
+            size += w as u64 * h as u64 * d as u64 * bpp as u64 / 8;
+
+            w >>= 1;
+            h >>= 1;
+            d >>= 1;
+        }
+
+        let size = size * num_layers as u64 * sides;
+        assert!(size <= std::u32::MAX as u64, "Texture storage size does not fit in 32 bits");
+
+        TextureInfo {
+            format: format,
+            storage_size: size as u32,
+            width: width,
+            height: height,
+            depth: depth,
+            num_layers: num_layers,
+            num_mips: num_mips,
+            bits_per_pixel: bpp as u8,
+            cube_map: cube_map,
+        }
+    }
+
+    #[inline]
+    fn from_raw(info: &bgfx_sys::bgfx_texture_info_t) -> TextureInfo {
+        TextureInfo {
+            format: unsafe { mem::transmute(info.format) },
+            storage_size: info.storageSize,
+            width: info.width,
+            height: info.height,
+            depth: info.depth,
+            num_layers: info.numLayers,
+            num_mips: info.numMips,
+            bits_per_pixel: info.bitsPerPixel,
+            cube_map: info.cubeMap,
+        }
+    }
+
+}
+
+/// Calculates the number of mip levels of a texture with the given dimensions, the same way bgfx
+/// does.
+#[inline]
+fn calc_num_mips(has_mips: bool, width: u16, height: u16, depth: u16) -> u8 {
+    if has_mips {
+        let max = std::cmp::max(std::cmp::max(width, height), depth);
+        (16 - max.leading_zeros()) as u8
+    } else {
+        1
+    }
+}
+
+/// Pending texture readback, created by [`Texture::read`].
+///
+/// The destination buffer is owned by the readback, and is handed back once the frame returned by
+/// [`Bgfx::frame`] reaches the frame the read completes on. Readbacks can either be polled with
+/// [`try_take`], or awaited as a `Future`.
+///
+/// If a readback is dropped before it has completed, its buffer is leaked, since the render thread
+/// may still write to it.
+///
+/// [`Texture::read`]: struct.Texture.html#method.read
+/// [`Bgfx::frame`]: struct.Bgfx.html#method.frame
+/// [`try_take`]: #method.try_take
+pub struct Readback<'b> {
+    bgfx: &'b Bgfx,
+    data: Option<Vec<u8>>,
+    frame: u32,
+}
+
+impl<'b> Readback<'b> {
+
+    /// Gets the frame number at which the data becomes available.
+    #[inline]
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Checks whether the data is available, based on the last frame number returned by
+    /// [`Bgfx::frame`].
+    ///
+    /// [`Bgfx::frame`]: struct.Bgfx.html#method.frame
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.bgfx.frame.get() >= self.frame
+    }
+
+    /// Takes the data, if it is available.
+    ///
+    /// Returns `None` if the data is not yet available, or has already been taken.
+    pub fn try_take(&mut self) -> Option<Vec<u8>> {
+        if self.is_ready() {
+            self.data.take()
+        } else {
+            None
+        }
+    }
+
+}
+
+impl<'b> Future for Readback<'b> {
+    type Output = Vec<u8>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Vec<u8>> {
+        let this = self.get_mut();
+        match this.try_take() {
+            Some(data) => Poll::Ready(data),
+            None => {
+                assert!(this.data.is_some(), "Readback polled after completion");
+                this.bgfx.wakers.borrow_mut().push(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<'b> Drop for Readback<'b> {
+
+    fn drop(&mut self) {
+        if let Some(data) = self.data.take() {
+            if !self.is_ready() {
+                mem::forget(data);
+            }
+        }
+    }
+
+}
+
+/// Frame buffer attachment, selecting a mip level and layer of a texture to render to.
+#[derive(Copy, Clone)]
+pub struct Attachment<'t> {
+    /// Texture to render to.
+    pub texture: &'t Texture<'t>,
+
+    /// Mip level of the texture to render to.
+    pub mip: u16,
+
+    /// Layer (or cube map side) of the texture to render to.
+    pub layer: u16,
+}
+
+impl<'t> Attachment<'t> {
+
+    /// Creates an attachment rendering to the base mip level of the first layer of a texture.
+    #[inline]
+    pub fn new(texture: &'t Texture<'t>) -> Attachment<'t> {
+        Attachment {
+            texture: texture,
+            mip: 0,
+            layer: 0,
+        }
+    }
+
+}
+
+enum FrameBufferTextures<'t> {
+    /// Textures created by bgfx, which are destroyed along with the frame buffer.
+    Internal(Vec<Texture<'t>>),
+
+    /// Textures owned by the frame buffer.
+    Owned(Vec<Texture<'t>>),
+
+    /// Textures borrowed by the frame buffer.
+    Borrowed(Vec<&'t Texture<'t>>),
+
+    /// Swap chain of a native window, along with the format of its depth buffer, which is
+    /// `UnknownDepth` if it has none. The window handle is only ever handed back to bgfx.
+    Window(usize, TextureFormat),
+}
+
+/// Frame buffer, used for rendering to textures.
+///
+/// A frame buffer either owns its attachment textures, or borrows them for as long as it lives.
+/// It is destroyed when dropped.
+pub struct FrameBuffer<'t> {
+    handle: bgfx_sys::bgfx_frame_buffer_handle_t,
+    textures: FrameBufferTextures<'t>,
+}
+
+impl<'t> FrameBuffer<'t> {
+
+    /// Creates a new frame buffer with a single texture attachment, of the given size and format.
+    pub fn new(_bgfx: &'t Bgfx,
+               width: u16,
+               height: u16,
+               format: TextureFormat,
+               flags: TextureFlags)
+               -> FrameBuffer<'t> {
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_frame_buffer(width,
+                                                            height,
+                                                            mem::transmute(format),
+                                                            flags.bits());
+            FrameBuffer::internal(handle, width, height, TextureKind::Texture2D, format, flags)
+        }
+    }
+
+    /// Creates a new frame buffer with a single texture attachment, which is sized relative to the
+    /// back buffer and automatically resized along with it.
+    ///
+    /// Since its size depends on the back buffer, the attachment texture reports a width and
+    /// height of `0`, and can't be read back or updated.
+    pub fn scaled(_bgfx: &'t Bgfx,
+                  ratio: BackbufferRatio,
+                  format: TextureFormat,
+                  flags: TextureFlags)
+                  -> FrameBuffer<'t> {
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_frame_buffer_scaled(mem::transmute(ratio),
+                                                                   mem::transmute(format),
+                                                                   flags.bits());
+            FrameBuffer::internal(handle, 0, 0, TextureKind::BackbufferScaled, format, flags)
+        }
+    }
+
+    /// Creates a new frame buffer that takes ownership of the given textures, and renders to
+    /// their base mip level and first layer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `textures` is empty, or has more textures than the renderer supports frame buffer
+    /// attachments.
+    pub fn from_textures(textures: Vec<Texture<'t>>) -> FrameBuffer<'t> {
+        check_attachment_count(textures.len());
+
+        let handles: Vec<_> = textures.iter().map(|texture| texture.handle).collect();
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_frame_buffer_from_handles(handles.len() as u8,
+                                                                         handles.as_ptr(),
+                                                                         false);
+            FrameBuffer {
+                handle: handle,
+                textures: FrameBufferTextures::Owned(textures),
+            }
+        }
+    }
+
+    /// Creates a new frame buffer rendering to the given attachments, which are borrowed for as
+    /// long as the frame buffer lives.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attachments` is empty, or has more attachments than the renderer supports, or if
+    /// any attachment's mip level or layer is out of range for its texture.
+    pub fn from_attachments(attachments: &[Attachment<'t>]) -> FrameBuffer<'t> {
+        check_attachment_count(attachments.len());
+
+        let raw: Vec<_> = attachments.iter()
+            .map(|attachment| {
+                let texture = attachment.texture;
+                let num_layers = match texture.kind {
+                    TextureKind::Texture2D |
+                    TextureKind::BackbufferScaled => texture.num_layers as u32,
+                    TextureKind::Texture3D => texture.depth as u32,
+                    TextureKind::Cube => texture.num_layers as u32 * 6,
+                };
+                assert!(attachment.mip < texture.num_mips as u16, "Mip level out of range");
+                assert!((attachment.layer as u32) < num_layers, "Layer out of range");
+
+                bgfx_sys::bgfx_attachment_t {
+                    handle: texture.handle,
+                    mip: attachment.mip,
+                    layer: attachment.layer,
+                }
+            })
+            .collect();
+
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_frame_buffer_from_attachment(raw.len() as u8,
+                                                                            raw.as_ptr(),
+                                                                            false);
+            FrameBuffer {
+                handle: handle,
+                textures: FrameBufferTextures::Borrowed(attachments.iter()
+                    .map(|attachment| attachment.texture)
+                    .collect()),
+            }
+        }
+    }
+
+    /// Creates a new frame buffer rendering to the swap chain of a native window, for rendering to
+    /// multiple windows.
+    ///
+    /// `nwh` is the native window handle, and `depth_format` the format of the depth buffer. If
+    /// `depth_format` is `None`, the frame buffer has no depth buffer. The frame buffer must be
+    /// recreated through [`resize`] whenever the window is resized.
+    ///
+    /// # Safety
+    ///
+    /// `nwh` must be a valid native window handle, and the window must outlive the frame buffer.
+    ///
+    /// [`resize`]: #method.resize
+    pub unsafe fn from_window(_bgfx: &'t Bgfx,
+                              nwh: *mut std::os::raw::c_void,
+                              width: u16,
+                              height: u16,
+                              depth_format: Option<TextureFormat>)
+                              -> FrameBuffer<'t> {
+        let depth_format = depth_format.unwrap_or(TextureFormat::UnknownDepth);
+        let handle = bgfx_sys::bgfx_create_frame_buffer_from_nwh(nwh,
+                                                                 width,
+                                                                 height,
+                                                                 mem::transmute(depth_format));
+        FrameBuffer {
+            handle: handle,
+            textures: FrameBufferTextures::Window(nwh as usize, depth_format),
+        }
+    }
+
+    /// Resizes a window frame buffer, by recreating its swap chain with the given size.
+    ///
+    /// Any views using this frame buffer must be updated through
+    /// [`Bgfx::set_view_frame_buffer`] after resizing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frame buffer was not created through [`from_window`].
+    ///
+    /// [`Bgfx::set_view_frame_buffer`]: struct.Bgfx.html#method.set_view_frame_buffer
+    /// [`from_window`]: #method.from_window
+    pub fn resize(&mut self, width: u16, height: u16) {
+        let (nwh, depth_format) = match self.textures {
+            FrameBufferTextures::Window(nwh, depth_format) => (nwh, depth_format),
+            _ => panic!("Only window frame buffers can be resized"),
+        };
+
+        unsafe {
+            bgfx_sys::bgfx_destroy_frame_buffer(self.handle);
+            self.handle = bgfx_sys::bgfx_create_frame_buffer_from_nwh(nwh as *mut _,
+                                                                      width,
+                                                                      height,
+                                                                      mem::transmute(depth_format));
+        }
+    }
+
+    /// Gets the texture of the given attachment, or `None` if there is no such attachment.
+    ///
+    /// Window frame buffers have no accessible textures.
+    pub fn texture(&self, attachment: usize) -> Option<&Texture<'t>> {
+        match self.textures {
+            FrameBufferTextures::Internal(ref textures) |
+            FrameBufferTextures::Owned(ref textures) => textures.get(attachment),
+            FrameBufferTextures::Borrowed(ref textures) => textures.get(attachment).map(|t| *t),
+            FrameBufferTextures::Window(..) => None,
+        }
+    }
+
+    /// Wraps a frame buffer whose single attachment texture was created by bgfx.
+    unsafe fn internal(handle: bgfx_sys::bgfx_frame_buffer_handle_t,
+                       width: u16,
+                       height: u16,
+                       kind: TextureKind,
+                       format: TextureFormat,
+                       flags: TextureFlags)
+                       -> FrameBuffer<'t> {
+        // bgfx makes the texture a render target, unless it's multisampled.
+        let flags = if flags.intersects(TEXTURE_RT_MSAA_MASK) { flags } else { flags | TEXTURE_RT };
+        let texture = Texture {
+            handle: bgfx_sys::bgfx_get_texture(handle, 0),
+            width: width,
+            height: height,
+            depth: 1,
+            num_layers: 1,
+            num_mips: 1,
+            format: format,
+            kind: kind,
+            flags: flags,
+            _phantom: PhantomData,
+        };
+
+        FrameBuffer {
+            handle: handle,
+            textures: FrameBufferTextures::Internal(vec![texture]),
+        }
+    }
+
+}
+
+impl<'t> Drop for FrameBuffer<'t> {
+
+    fn drop(&mut self) {
+        unsafe { bgfx_sys::bgfx_destroy_frame_buffer(self.handle) }
+
+        // Textures created by bgfx are destroyed along with the frame buffer.
+        if let FrameBufferTextures::Internal(ref mut textures) = self.textures {
+            for texture in textures.drain(..) {
+                mem::forget(texture);
+            }
+        }
+    }
+
+}
+
+/// Checks that the given number of frame buffer attachments is supported by the renderer.
+fn check_attachment_count(count: usize) {
+    let max_attachments = unsafe { (*bgfx_sys::bgfx_get_caps()).limits.maxFBAttachments };
+    assert!(count > 0, "Frame buffers need at least one attachment");
+    assert!(count as u32 <= max_attachments,
+            "Frame buffer has {} attachments, the renderer supports {}",
+            count,
+            max_attachments);
+}
+
+/// Shader uniform.
+///
+/// Uniforms are looked up by name in the shaders of a program, and their values are set through
+/// [`Bgfx::set_uniform`] before submitting a draw.
+///
+/// [`Bgfx::set_uniform`]: struct.Bgfx.html#method.set_uniform
+pub struct Uniform<'b> {
+    handle: bgfx_sys::bgfx_uniform_handle_t,
+    kind: UniformType,
+    num: u16,
+    _phantom: PhantomData<&'b Bgfx>,
+}
+
+impl<'b> Uniform<'b> {
+
+    /// Creates a new uniform with the given name and type. `num` is the number of array elements,
+    /// and should be `1` for non-array uniforms.
+    #[inline]
+    pub fn new(_bgfx: &'b Bgfx, name: &str, kind: UniformType, num: u16) -> Uniform<'b> {
+        let name = ffi::CString::new(name).unwrap();
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_uniform(name.as_ptr(), mem::transmute(kind), num);
+            Uniform { handle: handle, kind: kind, num: num, _phantom: PhantomData }
+        }
+    }
+
+    /// Gets the type of the uniform.
+    #[inline]
+    pub fn kind(&self) -> UniformType {
+        self.kind
+    }
+
+    /// Gets the number of array elements in the uniform.
+    #[inline]
+    pub fn num(&self) -> u16 {
+        self.num
+    }
+
+}
+
+impl<'b> Drop for Uniform<'b> {
+
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { bgfx_sys::bgfx_destroy_uniform(self.handle) }
+    }
+
+}
+
+/// Value that can be passed to [`Bgfx::set_uniform`].
+///
+/// This is implemented for `[f32; 4]` (`Vec4`), `[f32; 9]` (`Mat3`) and `[f32; 16]` (`Mat4`), as
+/// well as slices of those for setting uniform arrays. `Int1` uniforms are texture samplers, and
+/// are not set through this trait.
+///
+/// [`Bgfx::set_uniform`]: struct.Bgfx.html#method.set_uniform
+pub trait UniformValue: uniform_value::Sealed {
+
+    /// Gets the uniform type matching this value.
+    fn uniform_type() -> UniformType;
+
+    /// Gets the number of array elements in this value.
+    fn count(&self) -> usize;
+
+    /// Gets a pointer to the first element of this value.
+    fn as_ptr(&self) -> *const std::os::raw::c_void;
+
+}
+
+mod uniform_value {
+    pub trait Sealed {}
+
+    impl Sealed for [f32; 4] {}
+    impl Sealed for [f32; 9] {}
+    impl Sealed for [f32; 16] {}
+    impl Sealed for [[f32; 4]] {}
+    impl Sealed for [[f32; 9]] {}
+    impl Sealed for [[f32; 16]] {}
+}
+
+macro_rules! impl_uniform_value {
+    ($len:expr, $kind:expr) => (
+        impl UniformValue for [f32; $len] {
+            #[inline]
+            fn uniform_type() -> UniformType {
+                $kind
+            }
+
+            #[inline]
+            fn count(&self) -> usize {
+                1
+            }
+
+            #[inline]
+            fn as_ptr(&self) -> *const std::os::raw::c_void {
+                self as *const [f32; $len] as *const std::os::raw::c_void
+            }
+        }
+
+        impl UniformValue for [[f32; $len]] {
+            #[inline]
+            fn uniform_type() -> UniformType {
+                $kind
+            }
+
+            #[inline]
+            fn count(&self) -> usize {
+                self.len()
+            }
+
+            #[inline]
+            fn as_ptr(&self) -> *const std::os::raw::c_void {
+                <[[f32; $len]]>::as_ptr(self) as *const std::os::raw::c_void
+            }
+        }
+    );
+}
+
+impl_uniform_value!(4, UniformType::Vec4);
+impl_uniform_value!(9, UniformType::Mat3);
+impl_uniform_value!(16, UniformType::Mat4);
+
+/// Vertex index buffer.
+pub struct IndexBuffer<'m> {
+    handle: bgfx_sys::bgfx_index_buffer_handle_t,
+    _phantom: PhantomData<&'m ()>,
+}
+
+impl<'m> IndexBuffer<'m> {
+
+    /// Creates a new index buffer from bgfx-managed memory.
+    #[inline]
+    pub fn new(indices: Memory<'m>, flags: BufferFlags) -> IndexBuffer<'m> {
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_index_buffer(indices.handle, flags.bits());
+            IndexBuffer { handle: handle, _phantom: PhantomData }
+        }
+    }
+
+}
+
+impl<'m> Drop for IndexBuffer<'m> {
+
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { bgfx_sys::bgfx_destroy_index_buffer(self.handle) }
+    }
+
+}
+
+/// Dynamic vertex index buffer, whose contents can be updated after creation.
+pub struct DynamicIndexBuffer<'m> {
+    handle: bgfx_sys::bgfx_dynamic_index_buffer_handle_t,
+    num: u32,
+    flags: BufferFlags,
+    _phantom: PhantomData<&'m Bgfx>,
+}
+
+impl<'m> DynamicIndexBuffer<'m> {
+
+    /// Creates a new, uninitialized dynamic index buffer with room for `num` indices.
+    #[inline]
+    pub fn new(_bgfx: &'m Bgfx, num: u32, flags: BufferFlags) -> DynamicIndexBuffer<'m> {
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_dynamic_index_buffer(num, flags.bits());
+            DynamicIndexBuffer {
+                handle: handle,
+                num: num,
+                flags: flags,
+                _phantom: PhantomData,
+            }
+        }
+    }
+
+    /// Creates a new dynamic index buffer from bgfx-managed memory.
+    #[inline]
+    pub fn from_memory(indices: Memory<'m>, flags: BufferFlags) -> DynamicIndexBuffer<'m> {
+        let num = indices.size() / index_size(flags);
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_dynamic_index_buffer_mem(indices.handle,
+                                                                        flags.bits());
+            DynamicIndexBuffer {
+                handle: handle,
+                num: num,
+                flags: flags,
+                _phantom: PhantomData,
+            }
+        }
+    }
+
+    /// Gets the number of indices the buffer has room for.
+    #[inline]
+    pub fn num(&self) -> u32 {
+        self.num
+    }
+
+    /// Updates the contents of the buffer, starting at index `start_index`.
+    ///
+    /// If the buffer was created with `BUFFER_ALLOW_RESIZE`, it grows to fit the new indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `indices` is not a multiple of the index size, or if the indices
+    /// don't fit in the buffer and it was not created with `BUFFER_ALLOW_RESIZE`.
+    pub fn update(&mut self, start_index: u32, indices: Memory) {
+        self.num = check_dynamic_update(self.num,
+                                        self.flags,
+                                        start_index,
+                                        indices.size(),
+                                        index_size(self.flags));
+        unsafe {
+            bgfx_sys::bgfx_update_dynamic_index_buffer(self.handle, start_index, indices.handle)
+        }
+    }
+
+}
+
+impl<'m> Drop for DynamicIndexBuffer<'m> {
+
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { bgfx_sys::bgfx_destroy_dynamic_index_buffer(self.handle) }
+    }
+
+}
+
+/// Gets the size of a single index, for an index buffer with the given flags.
+#[inline]
+fn index_size(flags: BufferFlags) -> u32 {
+    if flags.contains(BUFFER_INDEX32) { 4 } else { 2 }
+}
+
+/// Validates an update of a dynamic buffer holding `num` elements of `stride` bytes, and returns
+/// the number of elements the buffer holds after the update.
+fn check_dynamic_update(num: u32, flags: BufferFlags, start: u32, size: u32, stride: u32) -> u32 {
+    assert!(stride > 0 && size % stride == 0,
+            "Update size {} is not a multiple of the element size {}",
+            size,
+            stride);
+
+    let end = start as u64 + (size / stride) as u64;
+    if end > num as u64 {
+        assert!(flags.contains(BUFFER_ALLOW_RESIZE),
+                "Update of elements {}..{} does not fit in the buffer of {} elements",
+                start,
+                end,
+                num);
+        end as u32
+    } else {
+        num
+    }
+}
+
+/// Vertex data buffer.
+pub struct VertexBuffer<'m> {
+    handle: bgfx_sys::bgfx_vertex_buffer_handle_t,
+    _phantom: PhantomData<&'m Bgfx>,
+}
+
+impl<'m> VertexBuffer<'m> {
+
+    /// Creates a new vertex buffer from bgfx-managed memory.
+    #[inline]
+    pub fn new<'v>(verts: Memory<'m>,
+                   decl: &'v VertexDecl,
+                   flags: BufferFlags)
+                   -> VertexBuffer<'m> {
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_vertex_buffer(verts.handle,
+                                                             &decl.decl,
+                                                             flags.bits());
+            VertexBuffer { handle: handle, _phantom: PhantomData }
+        }
+    }
+
+}
+
+impl<'m> Drop for VertexBuffer<'m> {
+
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { bgfx_sys::bgfx_destroy_vertex_buffer(self.handle) }
+    }
+
+}
+
+/// Dynamic vertex data buffer, whose contents can be updated after creation.
+pub struct DynamicVertexBuffer<'m> {
+    handle: bgfx_sys::bgfx_dynamic_vertex_buffer_handle_t,
+    num: u32,
+    stride: u16,
+    flags: BufferFlags,
+    _phantom: PhantomData<&'m Bgfx>,
+}
+
+impl<'m> DynamicVertexBuffer<'m> {
+
+    /// Creates a new, uninitialized dynamic vertex buffer with room for `num` vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decl` is empty.
+    #[inline]
+    pub fn new(_bgfx: &'m Bgfx,
+               num: u32,
+               decl: &VertexDecl,
+               flags: BufferFlags)
+               -> DynamicVertexBuffer<'m> {
+        assert!(decl.decl.stride > 0, "Vertex declaration is empty");
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_dynamic_vertex_buffer(num, &decl.decl, flags.bits());
+            DynamicVertexBuffer {
+                handle: handle,
+                num: num,
+                stride: decl.decl.stride,
+                flags: flags,
+                _phantom: PhantomData,
+            }
+        }
+    }
+
+    /// Creates a new dynamic vertex buffer from bgfx-managed memory.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decl` is empty.
+    #[inline]
+    pub fn from_memory(verts: Memory<'m>,
+                       decl: &VertexDecl,
+                       flags: BufferFlags)
+                       -> DynamicVertexBuffer<'m> {
+        assert!(decl.decl.stride > 0, "Vertex declaration is empty");
+        let num = verts.size() / decl.decl.stride as u32;
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_dynamic_vertex_buffer_mem(verts.handle,
+                                                                         &decl.decl,
+                                                                         flags.bits());
+            DynamicVertexBuffer {
+                handle: handle,
+                num: num,
+                stride: decl.decl.stride,
+                flags: flags,
+                _phantom: PhantomData,
+            }
+        }
+    }
+
+    /// Gets the number of vertices the buffer has room for.
+    #[inline]
+    pub fn num(&self) -> u32 {
+        self.num
+    }
+
+    /// Updates the contents of the buffer, starting at vertex `start_vertex`.
+    ///
+    /// If the buffer was created with `BUFFER_ALLOW_RESIZE`, it grows to fit the new vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `verts` is not a multiple of the vertex stride, or if the vertices
+    /// don't fit in the buffer and it was not created with `BUFFER_ALLOW_RESIZE`.
+    pub fn update(&mut self, start_vertex: u32, verts: Memory) {
+        self.num = check_dynamic_update(self.num,
+                                        self.flags,
+                                        start_vertex,
+                                        verts.size(),
+                                        self.stride as u32);
+        unsafe {
+            bgfx_sys::bgfx_update_dynamic_vertex_buffer(self.handle, start_vertex, verts.handle)
+        }
+    }
+
+}
+
+impl<'m> Drop for DynamicVertexBuffer<'m> {
+
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { bgfx_sys::bgfx_destroy_dynamic_vertex_buffer(self.handle) }
+    }
+
+}
+
+/// Indirect buffer, holding draw or dispatch commands written by compute shaders.
+///
+/// Compute shaders can write to the buffer once it has been bound through
+/// [`Bgfx::set_compute_indirect_buffer`], after which the commands can be executed through
+/// [`Bgfx::submit_indirect`] or [`Bgfx::dispatch_indirect`].
+///
+/// [`Bgfx::set_compute_indirect_buffer`]: struct.Bgfx.html#method.set_compute_indirect_buffer
+/// [`Bgfx::submit_indirect`]: struct.Bgfx.html#method.submit_indirect
+/// [`Bgfx::dispatch_indirect`]: struct.Bgfx.html#method.dispatch_indirect
+pub struct IndirectBuffer<'b> {
+    handle: bgfx_sys::bgfx_indirect_buffer_handle_t,
+    num: u32,
+    _phantom: PhantomData<&'b Bgfx>,
+}
+
+impl<'b> IndirectBuffer<'b> {
+
+    /// Creates a new indirect buffer with room for `num` commands.
+    #[inline]
+    pub fn new(_bgfx: &'b Bgfx, num: u32) -> IndirectBuffer<'b> {
+        unsafe {
+            let handle = bgfx_sys::bgfx_create_indirect_buffer(num);
+            IndirectBuffer { handle: handle, num: num, _phantom: PhantomData }
+        }
+    }
+
+    /// Gets the number of commands the buffer has room for.
+    #[inline]
+    pub fn num(&self) -> u32 {
+        self.num
+    }
+
+    /// Checks that the given range of commands is within the buffer.
+    #[inline]
+    fn check_range(&self, start: u16, num: u16) {
+        assert!(start as u32 + num as u32 <= self.num,
+                "Commands {}..{} are out of range for an indirect buffer of {} commands",
+                start,
+                start as u32 + num as u32,
+                self.num);
+    }
+
+}
+
+impl<'b> Drop for IndirectBuffer<'b> {
+
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { bgfx_sys::bgfx_destroy_indirect_buffer(self.handle) }
+    }
+
+}
+
+/// Frame being recorded on the main thread, which frame-scoped data is allocated from.
+///
+/// Transient buffers, instance data buffers and transforms only live until the end of the frame
+/// they are allocated in. They are allocated through the `alloc_*` methods, start out zeroed, and
+/// borrow the `Frame` they were allocated from. Since [`end`] consumes the frame, none of them can
+/// be used once bgfx has recycled their memory.
+///
+/// Only one `Frame` can exist at a time. It is created through [`Bgfx::begin_frame`], and
+/// [`Bgfx::frame`] panics while it is alive.
+///
+/// [`end`]: #method.end
+/// [`Bgfx::begin_frame`]: struct.Bgfx.html#method.begin_frame
+/// [`Bgfx::frame`]: struct.Bgfx.html#method.frame
+pub struct Frame<'b> {
+    bgfx: &'b Bgfx,
+}
+
+impl<'b> Frame<'b> {
+
+    /// Allocates an instance data buffer with room for `num` instances of `stride` bytes each.
+    ///
+    /// Returns `None` if there is not enough instance data buffer space left this frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is zero or not a multiple of 16.
+    pub fn alloc_instance_data_buffer<'f>(&'f self,
+                                          num: u32,
+                                          stride: u16)
+                                          -> Option<InstanceDataBuffer<'f>> {
+        assert!(stride > 0, "Instance data stride is zero");
+        assert!(stride % 16 == 0, "Instance data stride {} is not a multiple of 16", stride);
+        unsafe {
+            if bgfx_sys::bgfx_get_avail_instance_data_buffer(num, stride) < num {
+                return None;
+            }
 
-impl<'m> VertexBuffer<'m> {
+            let idb = bgfx_sys::bgfx_alloc_instance_data_buffer(num, stride);
+            ptr::write_bytes((*idb).data, 0, (*idb).size as usize);
+            Some(InstanceDataBuffer {
+                idb: idb,
+                num: (*idb).num,
+                stride: (*idb).stride,
+                _phantom: PhantomData,
+            })
+        }
+    }
 
-    /// Creates a new vertex buffer from bgfx-managed memory.
-    #[inline]
-    pub fn new<'v>(verts: Memory<'m>,
-                   decl: &'v VertexDecl,
-                   flags: BufferFlags)
-                   -> VertexBuffer<'m> {
+    /// Allocates `num` matrices in the transform cache.
+    ///
+    /// Returns `None` if there is not enough space left in the transform cache this frame.
+    pub fn alloc_transform<'f>(&'f self, num: u16) -> Option<Transform<'f>> {
         unsafe {
-            let handle = bgfx_sys::bgfx_create_vertex_buffer(verts.handle,
-                                                             &decl.decl,
-                                                             flags.bits());
-            VertexBuffer { handle: handle, _phantom: PhantomData }
+            let mut transform = mem::zeroed();
+            let cache = bgfx_sys::bgfx_alloc_transform(&mut transform, num);
+            if transform.num < num {
+                return None;
+            }
+
+            ptr::write_bytes(transform.data, 0, num as usize * 16);
+            Some(Transform {
+                transform: transform,
+                cache: cache,
+                _phantom: PhantomData,
+            })
+        }
+    }
+
+    /// Allocates a transient vertex buffer and a transient index buffer at the same time.
+    ///
+    /// Returns `None`, allocating neither, if there is not enough transient buffer space left for
+    /// both buffers this frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decl` is empty.
+    pub fn alloc_transient_buffers<'f>(&'f self,
+                                       decl: &VertexDecl,
+                                       num_vertices: u32,
+                                       num_indices: u32)
+                                       -> Option<(TransientVertexBuffer<'f>,
+                                                  TransientIndexBuffer<'f>)> {
+        assert!(decl.decl.stride > 0, "Vertex declaration is empty");
+        unsafe {
+            let mut tvb = mem::zeroed();
+            let mut tib = mem::zeroed();
+            if !bgfx_sys::bgfx_alloc_transient_buffers(&mut tvb,
+                                                       &decl.decl,
+                                                       num_vertices,
+                                                       &mut tib,
+                                                       num_indices) {
+                return None;
+            }
+
+            ptr::write_bytes(tvb.data, 0, tvb.size as usize);
+            ptr::write_bytes(tib.data, 0, tib.size as usize);
+            Some((TransientVertexBuffer { tvb: tvb, _phantom: PhantomData },
+                  TransientIndexBuffer { tib: tib, _phantom: PhantomData }))
+        }
+    }
+
+    /// Allocates a transient index buffer with room for `num` indices.
+    ///
+    /// Returns `None` if there is not enough transient index buffer space left this frame.
+    pub fn alloc_transient_index_buffer<'f>(&'f self,
+                                            num: u32)
+                                            -> Option<TransientIndexBuffer<'f>> {
+        unsafe {
+            if bgfx_sys::bgfx_get_avail_transient_index_buffer(num) < num {
+                return None;
+            }
+
+            let mut tib: bgfx_sys::bgfx_transient_index_buffer_t = mem::zeroed();
+            bgfx_sys::bgfx_alloc_transient_index_buffer(&mut tib, num);
+            ptr::write_bytes(tib.data, 0, tib.size as usize);
+            Some(TransientIndexBuffer {
+                tib: tib,
+                _phantom: PhantomData,
+            })
+        }
+    }
+
+    /// Allocates a transient vertex buffer with room for `num` vertices of the given declaration.
+    ///
+    /// Returns `None` if there is not enough transient vertex buffer space left this frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decl` is empty.
+    pub fn alloc_transient_vertex_buffer<'f>(&'f self,
+                                             num: u32,
+                                             decl: &VertexDecl)
+                                             -> Option<TransientVertexBuffer<'f>> {
+        assert!(decl.decl.stride > 0, "Vertex declaration is empty");
+        unsafe {
+            if bgfx_sys::bgfx_get_avail_transient_vertex_buffer(num, &decl.decl) < num {
+                return None;
+            }
+
+            let mut tvb: bgfx_sys::bgfx_transient_vertex_buffer_t = mem::zeroed();
+            bgfx_sys::bgfx_alloc_transient_vertex_buffer(&mut tvb, num, &decl.decl);
+            ptr::write_bytes(tvb.data, 0, tvb.size as usize);
+            Some(TransientVertexBuffer {
+                tvb: tvb,
+                _phantom: PhantomData,
+            })
         }
     }
 
+    /// Finishes the frame, syncing up with the render thread. Returns an incrementing frame
+    /// counter.
+    ///
+    /// See [`Bgfx::frame`].
+    ///
+    /// [`Bgfx::frame`]: struct.Bgfx.html#method.frame
+    pub fn end(self, capture: bool) -> u32 {
+        let bgfx = self.bgfx;
+        drop(self);
+        bgfx.frame(capture)
+    }
+
 }
 
-impl<'m> Drop for VertexBuffer<'m> {
+impl<'b> Drop for Frame<'b> {
 
     #[inline]
     fn drop(&mut self) {
-        unsafe { bgfx_sys::bgfx_destroy_vertex_buffer(self.handle) }
+        self.bgfx.frame_open.set(false);
+    }
+
+}
+
+/// Plain data, which can be accessed as a slice of frame-scoped buffer memory.
+///
+/// # Safety
+///
+/// Any bit pattern must be a valid value of the type, and the type must not contain any padding.
+/// This is the case for `#[repr(C)]` structs made up of integers and floats, with no gaps
+/// between them.
+pub unsafe trait Pod: Copy {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {
+        $(unsafe impl Pod for $ty {})*
+    }
+}
+
+impl_pod!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// Instance data buffer, holding per-instance data for a single [`Frame`].
+///
+/// [`Frame`]: struct.Frame.html
+pub struct InstanceDataBuffer<'f> {
+    idb: *const bgfx_sys::bgfx_instance_data_buffer_t,
+    num: u32,
+    stride: u16,
+    _phantom: PhantomData<&'f Frame<'f>>,
+}
+
+impl<'f> InstanceDataBuffer<'f> {
+
+    /// Gets the number of instances in the buffer.
+    #[inline]
+    pub fn num(&self) -> u32 {
+        self.num
+    }
+
+    /// Gets the size of the data of a single instance, in bytes.
+    #[inline]
+    pub fn stride(&self) -> u16 {
+        self.stride
+    }
+
+    /// Gets the raw contents of the buffer.
+    pub fn data(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut((*self.idb).data, (*self.idb).size as usize) }
+    }
+
+    /// Gets the instances of the buffer, as a slice of `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `T` does not match the instance stride, or if the buffer is not
+    /// suitably aligned for `T`.
+    pub fn instances<T: Pod>(&mut self) -> &mut [T] {
+        let data = unsafe { (*self.idb).data };
+        assert!(mem::size_of::<T>() == self.stride as usize,
+                "Size of instance type ({}) does not match the instance stride ({})",
+                mem::size_of::<T>(),
+                self.stride);
+        assert!(data as usize % mem::align_of::<T>() == 0,
+                "Buffer is not aligned for the instance type");
+        unsafe { std::slice::from_raw_parts_mut(data as *mut T, self.num as usize) }
+    }
+
+}
+
+/// Transient index buffer, holding 16-bit indices for a single [`Frame`].
+///
+/// [`Frame`]: struct.Frame.html
+pub struct TransientIndexBuffer<'f> {
+    tib: bgfx_sys::bgfx_transient_index_buffer_t,
+    _phantom: PhantomData<&'f Frame<'f>>,
+}
+
+impl<'f> TransientIndexBuffer<'f> {
+
+    /// Gets the number of indices in the buffer.
+    #[inline]
+    pub fn num(&self) -> u32 {
+        self.tib.size / 2
+    }
+
+    /// Gets the raw contents of the buffer.
+    pub fn data(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.tib.data, self.tib.size as usize) }
+    }
+
+    /// Gets the indices of the buffer.
+    pub fn indices(&mut self) -> &mut [u16] {
+        unsafe {
+            std::slice::from_raw_parts_mut(self.tib.data as *mut u16, self.num() as usize)
+        }
+    }
+
+}
+
+/// Transient vertex buffer, holding vertices for a single [`Frame`].
+///
+/// [`Frame`]: struct.Frame.html
+pub struct TransientVertexBuffer<'f> {
+    tvb: bgfx_sys::bgfx_transient_vertex_buffer_t,
+    _phantom: PhantomData<&'f Frame<'f>>,
+}
+
+impl<'f> TransientVertexBuffer<'f> {
+
+    /// Gets the number of vertices in the buffer.
+    #[inline]
+    pub fn num(&self) -> u32 {
+        self.tvb.size / self.tvb.stride as u32
+    }
+
+    /// Gets the size of a single vertex, in bytes.
+    #[inline]
+    pub fn stride(&self) -> u16 {
+        self.tvb.stride
+    }
+
+    /// Gets the raw contents of the buffer.
+    pub fn data(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.tvb.data, self.tvb.size as usize) }
+    }
+
+    /// Gets the vertices of the buffer, as a slice of `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `T` does not match the vertex stride, or if the buffer is not
+    /// suitably aligned for `T`.
+    pub fn vertices<T: Pod>(&mut self) -> &mut [T] {
+        assert!(mem::size_of::<T>() == self.tvb.stride as usize,
+                "Size of vertex type ({}) does not match the vertex stride ({})",
+                mem::size_of::<T>(),
+                self.tvb.stride);
+        assert!(self.tvb.data as usize % mem::align_of::<T>() == 0,
+                "Buffer is not aligned for the vertex type");
+        unsafe { std::slice::from_raw_parts_mut(self.tvb.data as *mut T, self.num() as usize) }
+    }
+
+}
+
+/// Block of model matrices in the transform cache, allocated for a single [`Frame`].
+///
+/// The matrices can be written directly, and used by any number of draws by passing [`cache`] to
+/// [`Bgfx::set_transform_cached`].
+///
+/// [`Bgfx::set_transform_cached`]: struct.Bgfx.html#method.set_transform_cached
+/// [`Frame`]: struct.Frame.html
+/// [`cache`]: #method.cache
+pub struct Transform<'f> {
+    transform: bgfx_sys::bgfx_transform_t,
+    cache: u32,
+    _phantom: PhantomData<&'f Frame<'f>>,
+}
+
+impl<'f> Transform<'f> {
+
+    /// Gets the location of the matrices in the transform cache.
+    #[inline]
+    pub fn cache(&self) -> TransformCache<'f> {
+        TransformCache {
+            cache: self.cache,
+            num: self.transform.num,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Gets the number of matrices.
+    #[inline]
+    pub fn num(&self) -> u16 {
+        self.transform.num
+    }
+
+    /// Gets the matrices, for writing.
+    pub fn matrices(&mut self) -> &mut [[f32; 16]] {
+        unsafe {
+            std::slice::from_raw_parts_mut(self.transform.data as *mut [f32; 16],
+                                           self.transform.num as usize)
+        }
+    }
+
+}
+
+/// Location of a block of matrices in the transform cache of a single [`Frame`].
+///
+/// Returned by [`Transform::cache`] and [`Bgfx::set_transform_slice`], and used by
+/// [`Bgfx::set_transform_cached`].
+///
+/// [`Bgfx::set_transform_cached`]: struct.Bgfx.html#method.set_transform_cached
+/// [`Bgfx::set_transform_slice`]: struct.Bgfx.html#method.set_transform_slice
+/// [`Frame`]: struct.Frame.html
+/// [`Transform::cache`]: struct.Transform.html#method.cache
+#[derive(Copy, Clone)]
+pub struct TransformCache<'f> {
+    cache: u32,
+    num: u16,
+    _phantom: PhantomData<&'f Frame<'f>>,
+}
+
+/// Location of a rectangle in the scissor cache, as returned by [`Bgfx::set_scissor`].
+///
+/// [`Bgfx::set_scissor`]: struct.Bgfx.html#method.set_scissor
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct ScissorCache(u16);
+
+impl ScissorCache {
+
+    /// Returns a location which disables scissoring when passed to
+    /// [`Bgfx::set_scissor_cached`].
+    ///
+    /// [`Bgfx::set_scissor_cached`]: struct.Bgfx.html#method.set_scissor_cached
+    #[inline]
+    pub fn none() -> ScissorCache {
+        ScissorCache(std::u16::MAX)
     }
 
 }
@@ -511,19 +2754,341 @@ impl VertexDeclBuilder {
             bgfx_sys::bgfx_vertex_decl_end(&mut self.decl);
         }
 
-        VertexDecl { decl: self.decl }
+        VertexDecl { decl: self.decl }
+    }
+
+    /// Indicates a gap in the vertex structure.
+    #[inline]
+    pub fn skip(&mut self, bytes: u8) -> &mut Self {
+        unsafe {
+            bgfx_sys::bgfx_vertex_decl_skip(&mut self.decl, bytes);
+        }
+
+        self
+    }
+
+}
+
+/// Renderer capabilities, as reported by [`Bgfx::caps`].
+///
+/// [`Bgfx::caps`]: struct.Bgfx.html#method.caps
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct Caps {
+    /// Type of the renderer in use.
+    pub renderer_type: RendererType,
+
+    /// Features supported by the renderer.
+    pub supported: CapsFlags,
+
+    /// PCI vendor ID of the selected GPU.
+    pub vendor_id: u16,
+
+    /// PCI device ID of the selected GPU.
+    pub device_id: u16,
+
+    /// Whether clip space depth ranges from -1 to 1, rather than from 0 to 1.
+    pub homogeneous_depth: bool,
+
+    /// Whether the texture and frame buffer origin is in the bottom left corner.
+    pub origin_bottom_left: bool,
+
+    /// GPUs present in the system.
+    pub gpus: Vec<CapsGpu>,
+
+    /// Renderer limits.
+    pub limits: CapsLimits,
+
+    formats: Vec<CapsFormatFlags>,
+}
+
+impl Caps {
+
+    /// Gets how the given texture format is supported by the renderer.
+    ///
+    /// Returns `CAPS_FORMAT_TEXTURE_NONE` if the format is not supported at all.
+    #[inline]
+    pub fn format_support(&self, format: TextureFormat) -> CapsFormatFlags {
+        self.formats.get(format as usize).cloned().unwrap_or(CAPS_FORMAT_TEXTURE_NONE)
+    }
+
+    fn from_raw(caps: &bgfx_sys::bgfx_caps_t) -> Caps {
+        let num_gpus = std::cmp::min(caps.numGPUs as usize, caps.gpu.len());
+        Caps {
+            renderer_type: unsafe { mem::transmute(caps.rendererType) },
+            supported: CapsFlags::from_bits_truncate(caps.supported),
+            vendor_id: caps.vendorId,
+            device_id: caps.deviceId,
+            homogeneous_depth: caps.homogeneousDepth,
+            origin_bottom_left: caps.originBottomLeft,
+            gpus: caps.gpu[..num_gpus].iter().map(CapsGpu::from_raw).collect(),
+            limits: CapsLimits::from_raw(&caps.limits),
+            formats: caps.formats.iter().map(|&f| CapsFormatFlags::from_bits_truncate(f)).collect(),
+        }
+    }
+
+}
+
+/// A GPU present in the system, as listed in [`Caps::gpus`].
+///
+/// [`Caps::gpus`]: struct.Caps.html#structfield.gpus
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct CapsGpu {
+    /// PCI vendor ID of the GPU.
+    pub vendor_id: u16,
+
+    /// PCI device ID of the GPU.
+    pub device_id: u16,
+}
+
+impl CapsGpu {
+
+    #[inline]
+    fn from_raw(gpu: &bgfx_sys::bgfx_caps_gpu_t) -> CapsGpu {
+        CapsGpu {
+            vendor_id: gpu.vendorId,
+            device_id: gpu.deviceId,
+        }
+    }
+
+}
+
+/// Renderer limits, as listed in [`Caps::limits`].
+///
+/// [`Caps::limits`]: struct.Caps.html#structfield.limits
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct CapsLimits {
+    /// Maximum number of draw calls per frame.
+    pub max_draw_calls: u32,
+
+    /// Maximum number of texture blits per frame.
+    pub max_blits: u32,
+
+    /// Maximum texture width and height, in pixels.
+    pub max_texture_size: u32,
+
+    /// Maximum number of views.
+    pub max_views: u32,
+
+    /// Maximum number of frame buffers.
+    pub max_frame_buffers: u32,
+
+    /// Maximum number of attachments per frame buffer.
+    pub max_fb_attachments: u32,
+
+    /// Maximum number of programs.
+    pub max_programs: u32,
+
+    /// Maximum number of shaders.
+    pub max_shaders: u32,
+
+    /// Maximum number of textures.
+    pub max_textures: u32,
+
+    /// Maximum number of texture samplers per draw call.
+    pub max_texture_samplers: u32,
+
+    /// Maximum number of vertex declarations.
+    pub max_vertex_decls: u32,
+
+    /// Maximum number of vertex streams per draw call.
+    pub max_vertex_streams: u32,
+
+    /// Maximum number of index buffers.
+    pub max_index_buffers: u32,
+
+    /// Maximum number of vertex buffers.
+    pub max_vertex_buffers: u32,
+
+    /// Maximum number of dynamic index buffers.
+    pub max_dynamic_index_buffers: u32,
+
+    /// Maximum number of dynamic vertex buffers.
+    pub max_dynamic_vertex_buffers: u32,
+
+    /// Maximum number of uniforms.
+    pub max_uniforms: u32,
+
+    /// Maximum number of occlusion queries.
+    pub max_occlusion_queries: u32,
+}
+
+impl CapsLimits {
+
+    fn from_raw(limits: &bgfx_sys::bgfx_caps_limits_t) -> CapsLimits {
+        CapsLimits {
+            max_draw_calls: limits.maxDrawCalls,
+            max_blits: limits.maxBlits,
+            max_texture_size: limits.maxTextureSize,
+            max_views: limits.maxViews,
+            max_frame_buffers: limits.maxFrameBuffers,
+            max_fb_attachments: limits.maxFBAttachments,
+            max_programs: limits.maxPrograms,
+            max_shaders: limits.maxShaders,
+            max_textures: limits.maxTextures,
+            max_texture_samplers: limits.maxTextureSamplers,
+            max_vertex_decls: limits.maxVertexDecls,
+            max_vertex_streams: limits.maxVertexStreams,
+            max_index_buffers: limits.maxIndexBuffers,
+            max_vertex_buffers: limits.maxVertexBuffers,
+            max_dynamic_index_buffers: limits.maxDynamicIndexBuffers,
+            max_dynamic_vertex_buffers: limits.maxDynamicVertexBuffers,
+            max_uniforms: limits.maxUniforms,
+            max_occlusion_queries: limits.maxOcclusionQueries,
+        }
+    }
+
+}
+
+/// Head-mounted display information, as reported by [`Bgfx::get_hmd`].
+///
+/// [`Bgfx::get_hmd`]: struct.Bgfx.html#method.get_hmd
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Hmd {
+    /// Per-eye information, left eye first.
+    pub eyes: [HmdEye; 2],
+
+    /// Render target width, in pixels.
+    pub width: u16,
+
+    /// Render target height, in pixels.
+    pub height: u16,
+
+    /// Display width of the device, in pixels.
+    pub device_width: u32,
+
+    /// Display height of the device, in pixels.
+    pub device_height: u32,
+
+    /// HMD status flags.
+    pub flags: HmdFlags,
+}
+
+impl Hmd {
+
+    fn from_raw(hmd: &bgfx_sys::bgfx_hmd_t) -> Hmd {
+        Hmd {
+            eyes: [HmdEye::from_raw(&hmd.eye[0]), HmdEye::from_raw(&hmd.eye[1])],
+            width: hmd.width,
+            height: hmd.height,
+            device_width: hmd.deviceWidth,
+            device_height: hmd.deviceHeight,
+            flags: HmdFlags::from_bits_truncate(hmd.flags),
+        }
+    }
+
+}
+
+/// Information about a single eye of a head-mounted display.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct HmdEye {
+    /// Eye rotation, as a quaternion.
+    pub rotation: [f32; 4],
+
+    /// Eye translation.
+    pub translation: [f32; 3],
+
+    /// Field of view, as the tangents of the up, down, left and right half-angles.
+    pub fov: [f32; 4],
+
+    /// Offset of the eye from the center of the head.
+    pub view_offset: [f32; 3],
+
+    /// Projection matrix of the eye.
+    pub projection: [f32; 16],
+
+    /// Number of pixels per unit of tangent angle, horizontally and vertically.
+    pub pixels_per_tan_angle: [f32; 2],
+}
+
+impl HmdEye {
+
+    #[inline]
+    fn from_raw(eye: &bgfx_sys::bgfx_hmd_eye_t) -> HmdEye {
+        HmdEye {
+            rotation: eye.rotation,
+            translation: eye.translation,
+            fov: eye.fov,
+            view_offset: eye.viewOffset,
+            projection: eye.projection,
+            pixels_per_tan_angle: eye.pixelsPerTanAngle,
+        }
+    }
+
+}
+
+/// Renderer statistics for the last frame, as reported by [`Bgfx::stats`].
+///
+/// [`Bgfx::stats`]: struct.Bgfx.html#method.stats
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub struct Stats {
+    /// Time spent by the CPU on the frame.
+    pub cpu_time: Duration,
+
+    /// Time spent by the GPU on the frame. This is zero if the renderer doesn't support GPU
+    /// timers.
+    pub gpu_time: Duration,
+
+    /// Time spent waiting for the render thread.
+    pub wait_render: Duration,
+
+    /// Time spent waiting for the submit thread.
+    pub wait_submit: Duration,
+
+    /// Number of draw calls submitted.
+    pub num_draw: u32,
+
+    /// Number of compute calls submitted.
+    pub num_compute: u32,
+
+    /// Maximum number of frames the GPU is behind the CPU.
+    pub max_gpu_latency: u32,
+
+    /// Backbuffer width, in pixels.
+    pub width: u16,
+
+    /// Backbuffer height, in pixels.
+    pub height: u16,
+
+    /// Debug text width, in characters.
+    pub text_width: u16,
+
+    /// Debug text height, in characters.
+    pub text_height: u16,
+}
+
+impl Stats {
+
+    fn from_raw(stats: &bgfx_sys::bgfx_stats_t) -> Stats {
+        let cpu_ticks = stats.cpuTimeEnd.saturating_sub(stats.cpuTimeBegin);
+        let gpu_ticks = stats.gpuTimeEnd.saturating_sub(stats.gpuTimeBegin);
+        Stats {
+            cpu_time: ticks_to_duration(cpu_ticks, stats.cpuTimerFreq),
+            gpu_time: ticks_to_duration(gpu_ticks, stats.gpuTimerFreq),
+            wait_render: ticks_to_duration(std::cmp::max(0, stats.waitRender) as u64,
+                                           stats.cpuTimerFreq),
+            wait_submit: ticks_to_duration(std::cmp::max(0, stats.waitSubmit) as u64,
+                                           stats.cpuTimerFreq),
+            num_draw: stats.numDraw,
+            num_compute: stats.numCompute,
+            max_gpu_latency: stats.maxGpuLatency,
+            width: stats.width,
+            height: stats.height,
+            text_width: stats.textWidth,
+            text_height: stats.textHeight,
+        }
     }
 
-    /// Indicates a gap in the vertex structure.
-    #[inline]
-    pub fn skip(&mut self, bytes: u8) -> &mut Self {
-        unsafe {
-            bgfx_sys::bgfx_vertex_decl_skip(&mut self.decl, bytes);
-        }
+}
 
-        self
+/// Converts a number of timer ticks to a duration, given the timer frequency in ticks per second.
+/// Returns a zero duration if the frequency is unknown.
+fn ticks_to_duration(ticks: u64, freq: u64) -> Duration {
+    if freq == 0 {
+        return Duration::new(0, 0);
     }
 
+    let nanos = (ticks % freq) as u128 * 1_000_000_000 / freq as u128;
+    Duration::new(ticks / freq, nanos as u32)
 }
 
 /// Acts as the library wrapper for bgfx. Any calls intended to be run on the main thread are
@@ -531,17 +3096,51 @@ impl VertexDeclBuilder {
 ///
 /// It is created through a call to [`bgfx::init`], and will shut down bgfx when dropped.
 ///
+/// `Bgfx` is `Send` but not `Sync`, since it keeps track of the current frame number and of the
+/// [`Readback`]s waiting on it. It can be moved to another thread, but not shared between threads.
+/// As a consequence, resources that borrow it, such as [`VertexBuffer`], [`Texture`],
+/// [`FrameBuffer`] and [`Uniform`], are neither `Send` nor `Sync`, and must stay on the thread
+/// that owns the `Bgfx`.
+///
 /// [`bgfx::init`]: fn.init.html
+/// [`Readback`]: struct.Readback.html
+/// [`VertexBuffer`]: struct.VertexBuffer.html
+/// [`Texture`]: struct.Texture.html
+/// [`FrameBuffer`]: struct.FrameBuffer.html
+/// [`Uniform`]: struct.Uniform.html
 pub struct Bgfx {
-    // This dummy field only exists so this type can't be publicly instantiated.
-    _dummy: u32,
+    frame: Cell<u32>,
+    frame_open: Cell<bool>,
+    wakers: RefCell<Vec<Waker>>,
 }
 
 impl Bgfx {
 
     #[inline]
     fn new() -> Bgfx {
-        Bgfx { _dummy: 0 }
+        Bgfx {
+            frame: Cell::new(0),
+            frame_open: Cell::new(false),
+            wakers: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Begins recording a frame, returning the [`Frame`] that frame-scoped data is allocated from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if another `Frame` is still alive.
+    ///
+    /// [`Frame`]: struct.Frame.html
+    pub fn begin_frame<'b>(&'b self) -> Frame<'b> {
+        assert!(!self.frame_open.get(), "A frame is already being recorded");
+        self.frame_open.set(true);
+        Frame { bgfx: self }
+    }
+
+    /// Gets the capabilities of the renderer in use.
+    pub fn caps(&self) -> Caps {
+        Caps::from_raw(unsafe { &*bgfx_sys::bgfx_get_caps() })
     }
 
     /// Clears the debug text overlay.
@@ -577,10 +3176,118 @@ impl Bgfx {
         unsafe { bgfx_sys::bgfx_dbg_text_printf(x, y, attr, text.as_ptr()) }
     }
 
-    /// Finish the frame, syncing up with the render thread. Returns an incrementing frame counter.
+    /// Dispatches a compute program to the given view, with the given number of thread groups.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `program` is not a compute program.
+    #[inline]
+    pub fn dispatch(&self,
+                    view: u8,
+                    program: &Program,
+                    num_x: u16,
+                    num_y: u16,
+                    num_z: u16,
+                    flags: SubmitFlags)
+                    -> u32 {
+        assert!(program.compute, "Only compute programs can be dispatched");
+        unsafe { bgfx_sys::bgfx_dispatch(view, program.handle, num_x, num_y, num_z, flags.bits()) }
+    }
+
+    /// Dispatches a compute program to the given view, reading the number of thread groups from
+    /// `num` commands of an indirect buffer, starting at command `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `program` is not a compute program, or if the commands are out of range for the
+    /// indirect buffer.
     #[inline]
+    pub fn dispatch_indirect(&self,
+                             view: u8,
+                             program: &Program,
+                             indirect: &IndirectBuffer,
+                             start: u16,
+                             num: u16,
+                             flags: SubmitFlags)
+                             -> u32 {
+        assert!(program.compute, "Only compute programs can be dispatched");
+        indirect.check_range(start, num);
+        unsafe {
+            bgfx_sys::bgfx_dispatch_indirect(view,
+                                             program.handle,
+                                             indirect.handle,
+                                             start,
+                                             num,
+                                             flags.bits())
+        }
+    }
+
+    /// Finish the frame, syncing up with the render thread. Returns an incrementing frame counter.
+    ///
+    /// Any pending [`Readback`] futures are woken up, so they can check whether their data has
+    /// become available.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`Frame`] is alive, since its frame-scoped data would outlive the frame. Use
+    /// [`Frame::end`] to finish the frame instead.
+    ///
+    /// [`Readback`]: struct.Readback.html
+    /// [`Frame`]: struct.Frame.html
+    /// [`Frame::end`]: struct.Frame.html#method.end
     pub fn frame(&self, capture: bool) -> u32 {
-        unsafe { bgfx_sys::bgfx_frame(capture) }
+        assert!(!self.frame_open.get(), "Frame ended while a Frame is still alive");
+        let frame = unsafe { bgfx_sys::bgfx_frame(capture) };
+        self.frame.set(frame);
+        let wakers = mem::replace(&mut *self.wakers.borrow_mut(), Vec::new());
+        for waker in wakers {
+            waker.wake();
+        }
+        frame
+    }
+
+    /// Returns the number of instances, up to `num`, that can be allocated in an instance data
+    /// buffer with the given stride this frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is zero.
+    #[inline]
+    pub fn get_avail_instance_data_buffer(&self, num: u32, stride: u16) -> u32 {
+        assert!(stride > 0, "Instance data stride is zero");
+        unsafe { bgfx_sys::bgfx_get_avail_instance_data_buffer(num, stride) }
+    }
+
+    /// Returns the number of indices, up to `num`, that can be allocated in a transient index
+    /// buffer this frame.
+    #[inline]
+    pub fn get_avail_transient_index_buffer(&self, num: u32) -> u32 {
+        unsafe { bgfx_sys::bgfx_get_avail_transient_index_buffer(num) }
+    }
+
+    /// Returns the number of vertices of the given declaration, up to `num`, that can be
+    /// allocated in a transient vertex buffer this frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decl` is empty.
+    #[inline]
+    pub fn get_avail_transient_vertex_buffer(&self, num: u32, decl: &VertexDecl) -> u32 {
+        assert!(decl.decl.stride > 0, "Vertex declaration is empty");
+        unsafe { bgfx_sys::bgfx_get_avail_transient_vertex_buffer(num, &decl.decl) }
+    }
+
+    /// Gets information about the head-mounted display in use.
+    ///
+    /// Returns `None` if no HMD is available, which is always the case with the `Noop` renderer.
+    pub fn get_hmd(&self) -> Option<Hmd> {
+        let hmd = unsafe { bgfx_sys::bgfx_get_hmd() };
+        if hmd.is_null() {
+            return None;
+        }
+
+        let hmd = Hmd::from_raw(unsafe { &*hmd });
+        if hmd.flags.is_empty() { None } else { Some(hmd) }
     }
 
     /// Gets the type of the renderer in use.
@@ -595,12 +3302,133 @@ impl Bgfx {
         unsafe { bgfx_sys::bgfx_reset(width as u32, height as u32, reset.bits()) }
     }
 
+    /// Resets the given view to its default state.
+    #[inline]
+    pub fn reset_view(&self, id: u8) {
+        unsafe { bgfx_sys::bgfx_reset_view(id) }
+    }
+
+    /// Sets a dynamic index buffer to be used by a compute shader.
+    #[inline]
+    pub fn set_compute_dynamic_index_buffer(&self,
+                                            stage: u8,
+                                            ibh: &DynamicIndexBuffer,
+                                            access: Access) {
+        // TODO: How to solve lifetimes...
+        unsafe {
+            bgfx_sys::bgfx_set_compute_dynamic_index_buffer(stage,
+                                                            ibh.handle,
+                                                            mem::transmute(access))
+        }
+    }
+
+    /// Sets a dynamic vertex buffer to be used by a compute shader.
+    #[inline]
+    pub fn set_compute_dynamic_vertex_buffer(&self,
+                                             stage: u8,
+                                             vbh: &DynamicVertexBuffer,
+                                             access: Access) {
+        // TODO: How to solve lifetimes...
+        unsafe {
+            bgfx_sys::bgfx_set_compute_dynamic_vertex_buffer(stage,
+                                                             vbh.handle,
+                                                             mem::transmute(access))
+        }
+    }
+
+    /// Sets an index buffer to be used by a compute shader.
+    #[inline]
+    pub fn set_compute_index_buffer(&self, stage: u8, ibh: &IndexBuffer, access: Access) {
+        // TODO: How to solve lifetimes...
+        unsafe {
+            bgfx_sys::bgfx_set_compute_index_buffer(stage, ibh.handle, mem::transmute(access))
+        }
+    }
+
+    /// Sets an indirect buffer to be used by a compute shader.
+    #[inline]
+    pub fn set_compute_indirect_buffer(&self,
+                                       stage: u8,
+                                       indirect: &IndirectBuffer,
+                                       access: Access) {
+        // TODO: How to solve lifetimes...
+        unsafe {
+            bgfx_sys::bgfx_set_compute_indirect_buffer(stage,
+                                                       indirect.handle,
+                                                       mem::transmute(access))
+        }
+    }
+
+    /// Sets a vertex buffer to be used by a compute shader.
+    #[inline]
+    pub fn set_compute_vertex_buffer(&self, stage: u8, vbh: &VertexBuffer, access: Access) {
+        // TODO: How to solve lifetimes...
+        unsafe {
+            bgfx_sys::bgfx_set_compute_vertex_buffer(stage, vbh.handle, mem::transmute(access))
+        }
+    }
+
+    /// Sets a condition for the next draw, which is only rendered if the result of the given
+    /// occlusion query matches `visible`.
+    #[inline]
+    pub fn set_condition(&self, query: &OcclusionQuery, visible: bool) {
+        unsafe { bgfx_sys::bgfx_set_condition(query.handle, visible) }
+    }
+
     /// Sets the debug flags to use.
     #[inline]
     pub fn set_debug(&self, debug: DebugFlags) {
         unsafe { bgfx_sys::bgfx_set_debug(debug.bits()) }
     }
 
+    /// Sets the dynamic index buffer to use for rendering.
+    #[inline]
+    pub fn set_dynamic_index_buffer(&self, ibh: &DynamicIndexBuffer) {
+        // TODO: How to solve lifetimes...
+        unsafe { bgfx_sys::bgfx_set_dynamic_index_buffer(ibh.handle, 0, std::u32::MAX) }
+    }
+
+    /// Sets the dynamic vertex buffer to use for rendering.
+    #[inline]
+    pub fn set_dynamic_vertex_buffer(&self, stream: u8, vbh: &DynamicVertexBuffer) {
+        // TODO: How to solve lifetimes...
+        unsafe { bgfx_sys::bgfx_set_dynamic_vertex_buffer(stream, vbh.handle, 0, std::u32::MAX) }
+    }
+
+    /// Sets a mip level of a texture as an image to be used by a compute shader.
+    ///
+    /// If `format` is `None`, the format of the texture is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sampler` is not an `Int1` uniform, or if `mip` is out of range for the texture.
+    #[inline]
+    pub fn set_image(&self,
+                     stage: u8,
+                     sampler: &Uniform,
+                     texture: &Texture,
+                     mip: u8,
+                     access: Access,
+                     format: Option<TextureFormat>) {
+        // TODO: How to solve lifetimes...
+        assert!(sampler.kind == UniformType::Int1,
+                "Image sampler uniforms must be of type Int1");
+        assert!(mip < texture.num_mips, "Mip level out of range");
+
+        unsafe {
+            let format = match format {
+                Some(format) => mem::transmute(format),
+                None => bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_COUNT,
+            };
+            bgfx_sys::bgfx_set_image(stage,
+                                     sampler.handle,
+                                     texture.handle,
+                                     mip,
+                                     mem::transmute(access),
+                                     format)
+        }
+    }
+
     /// Sets the index buffer to use for rendering.
     #[inline]
     pub fn set_index_buffer(&self, ibh: &IndexBuffer) {
@@ -608,12 +3436,115 @@ impl Bgfx {
         unsafe { bgfx_sys::bgfx_set_index_buffer(ibh.handle, 0, std::u32::MAX) }
     }
 
+    /// Sets the instance data buffer to use for rendering.
+    #[inline]
+    pub fn set_instance_data_buffer(&self, idb: &InstanceDataBuffer) {
+        unsafe { bgfx_sys::bgfx_set_instance_data_buffer(idb.idb, std::u32::MAX) }
+    }
+
+    /// Sets a range of a dynamic vertex buffer as the instance data to use for rendering.
+    #[inline]
+    pub fn set_instance_data_from_dynamic_vertex_buffer(&self,
+                                                        vbh: &DynamicVertexBuffer,
+                                                        start_vertex: u32,
+                                                        num: u32) {
+        // TODO: How to solve lifetimes...
+        unsafe {
+            bgfx_sys::bgfx_set_instance_data_from_dynamic_vertex_buffer(vbh.handle,
+                                                                        start_vertex,
+                                                                        num)
+        }
+    }
+
+    /// Sets a range of a vertex buffer as the instance data to use for rendering.
+    #[inline]
+    pub fn set_instance_data_from_vertex_buffer(&self,
+                                                vbh: &VertexBuffer,
+                                                start_vertex: u32,
+                                                num: u32) {
+        // TODO: How to solve lifetimes...
+        unsafe {
+            bgfx_sys::bgfx_set_instance_data_from_vertex_buffer(vbh.handle, start_vertex, num)
+        }
+    }
+
+    /// Sets a color in the palette used by [`set_view_clear_mrt`] and texture border colors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not below 16, the size of the palette.
+    ///
+    /// [`set_view_clear_mrt`]: #method.set_view_clear_mrt
+    #[inline]
+    pub fn set_palette_color(&self, index: u8, rgba: [f32; 4]) {
+        assert!(index < 16, "Palette index {} is out of range", index);
+        unsafe { bgfx_sys::bgfx_set_palette_color(index, rgba[..].as_ptr()) }
+    }
+
+    /// Sets the scissor rectangle for the next draw. Returns the location of the rectangle in the
+    /// scissor cache, which can be reused through [`set_scissor_cached`] for the rest of the
+    /// frame.
+    ///
+    /// [`set_scissor_cached`]: #method.set_scissor_cached
+    #[inline]
+    pub fn set_scissor(&self, x: u16, y: u16, width: u16, height: u16) -> ScissorCache {
+        ScissorCache(unsafe { bgfx_sys::bgfx_set_scissor(x, y, width, height) })
+    }
+
+    /// Sets the scissor rectangle for the next draw to a rectangle in the scissor cache, as
+    /// returned by [`set_scissor`]. Passing [`ScissorCache::none`] disables scissoring.
+    ///
+    /// [`ScissorCache::none`]: struct.ScissorCache.html#method.none
+    /// [`set_scissor`]: #method.set_scissor
+    #[inline]
+    pub fn set_scissor_cached(&self, cache: ScissorCache) {
+        unsafe { bgfx_sys::bgfx_set_scissor_cached(cache.0) }
+    }
+
     /// Sets the render state.
     #[inline]
     pub fn set_state(&self, state: StateFlags, rgba: Option<u32>) {
         unsafe { bgfx_sys::bgfx_set_state(state.bits(), rgba.unwrap_or(0)) }
     }
 
+    /// Sets the stencil state for front faces, and optionally back faces. If `back` is `None`, the
+    /// front face state is used for both faces.
+    #[inline]
+    pub fn set_stencil(&self, front: StencilState, back: Option<StencilState>) {
+        let back = back.map_or(bgfx_sys::BGFX_STENCIL_NONE, |back| back.bits());
+        unsafe { bgfx_sys::bgfx_set_stencil(front.bits(), back) }
+    }
+
+    /// Binds a texture to a sampler stage for the next draw.
+    ///
+    /// `sampler` must be an `Int1` uniform naming the sampler in the shader. If `flags` is given,
+    /// its sampler flags (wrap, filtering and compare modes) override the ones the texture was
+    /// created with, for this draw only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stage` is not below the `maxTextureSamplers` limit of the renderer, or if
+    /// `sampler` is not an `Int1` uniform.
+    #[inline]
+    pub fn set_texture(&self,
+                       stage: u8,
+                       sampler: &Uniform,
+                       texture: &Texture,
+                       flags: Option<TextureFlags>) {
+        // TODO: How to solve lifetimes...
+        let max_samplers = unsafe { (*bgfx_sys::bgfx_get_caps()).limits.maxTextureSamplers };
+        assert!((stage as u32) < max_samplers,
+                "Texture stage {} is out of range, the renderer supports {} samplers",
+                stage,
+                max_samplers);
+        assert!(sampler.kind == UniformType::Int1,
+                "Texture samplers must be Int1 uniforms, got {:?}",
+                sampler.kind);
+
+        let flags = flags.map_or(std::u32::MAX, |flags| flags.bits());
+        unsafe { bgfx_sys::bgfx_set_texture(stage, sampler.handle, texture.handle, flags) }
+    }
+
     /// Sets the model transform for rendering. If not called before submitting a draw, an identity
     /// matrix will be used.
     #[inline]
@@ -623,6 +3554,73 @@ impl Bgfx {
         }
     }
 
+    /// Sets the model transform for rendering to matrices already in the transform cache.
+    #[inline]
+    pub fn set_transform_cached(&self, cache: TransformCache) {
+        unsafe { bgfx_sys::bgfx_set_transform_cached(cache.cache, cache.num) }
+    }
+
+    /// Sets multiple model matrices for rendering, such as a bone palette for skinning. Returns
+    /// their location in the transform cache, so that later draws this frame can reuse them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mtx` is empty, or has more than `std::u16::MAX` matrices.
+    #[inline]
+    pub fn set_transform_slice<'f>(&self,
+                                   _frame: &'f Frame,
+                                   mtx: &[[f32; 16]])
+                                   -> TransformCache<'f> {
+        assert!(!mtx.is_empty() && mtx.len() <= std::u16::MAX as usize,
+                "Invalid number of matrices: {}",
+                mtx.len());
+        let cache = unsafe {
+            bgfx_sys::bgfx_set_transform(mtx.as_ptr() as *const std::os::raw::c_void,
+                                         mtx.len() as u16)
+        };
+        TransformCache {
+            cache: cache,
+            num: mtx.len() as u16,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Sets the transient index buffer to use for rendering.
+    #[inline]
+    pub fn set_transient_index_buffer(&self, tib: &TransientIndexBuffer) {
+        unsafe { bgfx_sys::bgfx_set_transient_index_buffer(&tib.tib, 0, std::u32::MAX) }
+    }
+
+    /// Sets the transient vertex buffer to use for rendering.
+    #[inline]
+    pub fn set_transient_vertex_buffer(&self, stream: u8, tvb: &TransientVertexBuffer) {
+        unsafe { bgfx_sys::bgfx_set_transient_vertex_buffer(stream, &tvb.tvb, 0, std::u32::MAX) }
+    }
+
+    /// Sets the value of a uniform for the next draw.
+    ///
+    /// The value can be a single `[f32; 4]`, `[f32; 9]` or `[f32; 16]`, or a slice of those when
+    /// setting an array uniform. Setting fewer elements than the uniform holds only updates the
+    /// leading elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value does not match the type of the uniform, or if it holds more elements
+    /// than the uniform was created with.
+    #[inline]
+    pub fn set_uniform<V: UniformValue + ?Sized>(&self, uniform: &Uniform, value: &V) {
+        let count = value.count();
+        assert!(V::uniform_type() == uniform.kind,
+                "Uniform of type {:?} can't be set to a {:?} value",
+                uniform.kind,
+                V::uniform_type());
+        assert!(count <= uniform.num as usize,
+                "Uniform holds {} elements, but {} were given",
+                uniform.num,
+                count);
+        unsafe { bgfx_sys::bgfx_set_uniform(uniform.handle, value.as_ptr(), count as u16) }
+    }
+
     /// Sets the vertex buffer to use for rendering.
     #[inline]
     pub fn set_vertex_buffer(&self, stream: u8, vbh: &VertexBuffer) {
@@ -636,12 +3634,131 @@ impl Bgfx {
         unsafe { bgfx_sys::bgfx_set_view_clear(id, flags.bits(), rgba, depth, stencil) }
     }
 
+    /// Sets the options to use when clearing the given view, when rendering to multiple render
+    /// targets.
+    ///
+    /// Each entry of `colors` is the palette index of the clear color of the corresponding
+    /// attachment, as set through [`set_palette_color`]. Attachments past the end of `colors`, or
+    /// whose entry is `std::u8::MAX`, use no palette color.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `colors` has more than 8 entries, or if an entry is neither below 16 nor
+    /// `std::u8::MAX`.
+    ///
+    /// [`set_palette_color`]: #method.set_palette_color
+    pub fn set_view_clear_mrt(&self,
+                              id: u8,
+                              flags: ClearFlags,
+                              depth: f32,
+                              stencil: u8,
+                              colors: &[u8]) {
+        assert!(colors.len() <= 8,
+                "At most 8 attachments can be cleared, got {}",
+                colors.len());
+        for &index in colors {
+            assert!(index < 16 || index == std::u8::MAX,
+                    "Palette index {} is out of range",
+                    index);
+        }
+
+        let mut c = [std::u8::MAX; 8];
+        c[..colors.len()].copy_from_slice(colors);
+        unsafe {
+            bgfx_sys::bgfx_set_view_clear_mrt(id,
+                                              flags.bits(),
+                                              depth,
+                                              stencil,
+                                              c[0],
+                                              c[1],
+                                              c[2],
+                                              c[3],
+                                              c[4],
+                                              c[5],
+                                              c[6],
+                                              c[7])
+        }
+    }
+
+    /// Sets the frame buffer the given view renders to. If `None`, the view renders to the back
+    /// buffer.
+    #[inline]
+    pub fn set_view_frame_buffer(&self, id: u8, frame_buffer: Option<&FrameBuffer>) {
+        let invalid = bgfx_sys::bgfx_frame_buffer_handle_t { idx: std::u16::MAX };
+        let handle = frame_buffer.map_or(invalid, |frame_buffer| frame_buffer.handle);
+        unsafe { bgfx_sys::bgfx_set_view_frame_buffer(id, handle) }
+    }
+
+    /// Sets the name of the given view, shown in graphics debuggers and profilers.
+    #[inline]
+    pub fn set_view_name(&self, id: u8, name: &str) {
+        let name = ffi::CString::new(name).unwrap();
+        unsafe { bgfx_sys::bgfx_set_view_name(id, name.as_ptr()) }
+    }
+
+    /// Remaps the order in which views are rendered, starting at view `first`. View
+    /// `first + i` is rendered in the place of view `order[i]`.
+    ///
+    /// If `order` is `None`, the order of all views starting at `first` is reset to their default.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `order` goes past the last view, or has more than `std::u8::MAX` entries.
+    #[inline]
+    pub fn set_view_order(&self, first: u8, order: Option<&[u8]>) {
+        match order {
+            Some(order) => {
+                assert!(order.len() <= std::u8::MAX as usize &&
+                        first as usize + order.len() <= std::u8::MAX as usize + 1,
+                        "View order goes past the last view");
+                unsafe {
+                    bgfx_sys::bgfx_set_view_order(first,
+                                                  order.len() as u8,
+                                                  order.as_ptr() as *const std::os::raw::c_void)
+                }
+            }
+            None => {
+                let max_views = unsafe { (*bgfx_sys::bgfx_get_caps()).limits.maxViews };
+                let end = std::cmp::min(max_views, std::u8::MAX as u32 + 1);
+
+                // The view count is a `u8`, so resetting every view takes more than one call.
+                let mut view = first as u32;
+                while view < end {
+                    let num = std::cmp::min(end - view, std::u8::MAX as u32);
+                    unsafe { bgfx_sys::bgfx_set_view_order(view as u8, num as u8, ptr::null()) }
+                    view += num;
+                }
+            }
+        }
+    }
+
     /// Sets the rectangle to display the given view in.
     #[inline]
     pub fn set_view_rect(&self, id: u8, x: u16, y: u16, width: u16, height: u16) {
         unsafe { bgfx_sys::bgfx_set_view_rect(id, x, y, width, height) }
     }
 
+    /// Sets the rectangle to display the given view in, sized relative to the back buffer.
+    #[inline]
+    pub fn set_view_rect_auto(&self, id: u8, x: u16, y: u16, ratio: BackbufferRatio) {
+        unsafe { bgfx_sys::bgfx_set_view_rect_auto(id, x, y, mem::transmute(ratio)) }
+    }
+
+    /// Sets the scissor rectangle of the given view. If `rect` is `None`, scissoring is disabled
+    /// for the view.
+    #[inline]
+    pub fn set_view_scissor(&self, id: u8, rect: Option<Rect>) {
+        let rect = rect.unwrap_or_default();
+        unsafe { bgfx_sys::bgfx_set_view_scissor(id, rect.x, rect.y, rect.width, rect.height) }
+    }
+
+    /// Sets whether draw calls in the given view are rendered in the order they were submitted,
+    /// rather than sorted.
+    #[inline]
+    pub fn set_view_seq(&self, id: u8, enabled: bool) {
+        unsafe { bgfx_sys::bgfx_set_view_seq(id, enabled) }
+    }
+
     /// Sets the view and projection matrices for the given view.
     #[inline]
     pub fn set_view_transform(&self, id: u8, view: &[f32; 16], proj: &[f32; 16]) {
@@ -652,12 +3769,90 @@ impl Bgfx {
         }
     }
 
+    /// Sets the view and projection matrices for the given view, when rendering in stereo.
+    ///
+    /// `proj_l` is the projection of the left eye, and `proj_r` that of the right eye. If
+    /// `proj_r` is `None`, `proj_l` is used for both eyes.
+    pub fn set_view_transform_stereo(&self,
+                                     id: u8,
+                                     view: &[f32; 16],
+                                     proj_l: &[f32; 16],
+                                     flags: ViewFlags,
+                                     proj_r: Option<&[f32; 16]>) {
+        let proj_r = proj_r.map_or(ptr::null(), |p| p.as_ptr() as *const std::os::raw::c_void);
+        unsafe {
+            bgfx_sys::bgfx_set_view_transform_stereo(id,
+                                                     view.as_ptr() as *const std::os::raw::c_void,
+                                                     proj_l.as_ptr() as *const std::os::raw::c_void,
+                                                     flags.bits(),
+                                                     proj_r)
+        }
+    }
+
+    /// Gets the renderer statistics of the last frame.
+    pub fn stats(&self) -> Stats {
+        Stats::from_raw(unsafe { &*bgfx_sys::bgfx_get_stats() })
+    }
+
     /// Submit a primitive for rendering. Returns the number of draw calls used.
     #[inline]
     pub fn submit(&self, view: u8, program: &Program, preserve_state: bool) -> u32 {
         unsafe { bgfx_sys::bgfx_submit(view, program.handle, 0, preserve_state) }
     }
 
+    /// Submits `num` draw commands of an indirect buffer for rendering, starting at command
+    /// `start`. Returns the number of draw calls used.
+    ///
+    /// `depth` is used for sorting draw calls within the view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `program` is a compute program, or if the commands are out of range for the
+    /// indirect buffer.
+    #[inline]
+    pub fn submit_indirect(&self,
+                           view: u8,
+                           program: &Program,
+                           indirect: &IndirectBuffer,
+                           start: u16,
+                           num: u16,
+                           depth: i32,
+                           preserve_state: bool)
+                           -> u32 {
+        assert!(!program.compute, "Compute programs can't be submitted for rendering");
+        indirect.check_range(start, num);
+        unsafe {
+            bgfx_sys::bgfx_submit_indirect(view,
+                                           program.handle,
+                                           indirect.handle,
+                                           start,
+                                           num,
+                                           depth,
+                                           preserve_state)
+        }
+    }
+
+    /// Submits a primitive for rendering along with an occlusion query, whose result can be used
+    /// to conditionally render later draws. Returns the number of draw calls used.
+    ///
+    /// `depth` is used for sorting draw calls within the view.
+    #[inline]
+    pub fn submit_occlusion_query(&self,
+                                  view: u8,
+                                  program: &Program,
+                                  query: &OcclusionQuery,
+                                  depth: i32,
+                                  preserve_state: bool)
+                                  -> u32 {
+        unsafe {
+            bgfx_sys::bgfx_submit_occlusion_query(view,
+                                                  program.handle,
+                                                  query.handle,
+                                                  depth,
+                                                  preserve_state)
+        }
+    }
+
     /// Touches a view. ( ͡° ͜ʖ ͡°)
     #[inline]
     pub fn touch(&self, id: u8) {
@@ -685,6 +3880,20 @@ pub fn render_frame() -> RenderFrame {
     unsafe { mem::transmute(bgfx_sys::bgfx_render_frame()) }
 }
 
+/// Gets the renderers supported on this platform.
+///
+/// This can be called before bgfx is initialized.
+pub fn supported_renderers() -> Vec<RendererType> {
+    let max = bgfx_sys::bgfx_renderer_type_BGFX_RENDERER_TYPE_COUNT as usize;
+    let mut renderers = vec![bgfx_sys::bgfx_renderer_type_BGFX_RENDERER_TYPE_NOOP; max];
+    let num = unsafe {
+        bgfx_sys::bgfx_get_supported_renderers(max as u8, renderers.as_mut_ptr())
+    };
+
+    renderers.truncate(num as usize);
+    renderers.into_iter().map(|renderer| unsafe { mem::transmute(renderer) }).collect()
+}
+
 /// Platform data initializer.
 ///
 /// This should be applied *only once*, before bgfx is used.
@@ -781,3 +3990,129 @@ pub fn init(renderer: RendererType,
         if success { Ok(Bgfx::new()) } else { Err(BgfxError::InitFailed) }
     }
 }
+
+/// Initializes bgfx with the first renderer of `renderers` that is supported on this platform and
+/// initializes successfully.
+///
+/// On success, returns the renderer that ended up in use along with the library wrapper. Use
+/// `RendererType::Default` to let bgfx pick, and `RendererType::Noop` as a last resort. Fails with
+/// `BgfxError::InitFailed` if none of the renderers could be initialized. See [`init`].
+///
+/// [`init`]: fn.init.html
+pub fn init_with_fallback(renderers: &[RendererType],
+                          vendor_id: Option<u16>,
+                          device_id: Option<u16>)
+                          -> Result<(Bgfx, RendererType), BgfxError> {
+    let supported = supported_renderers();
+    for &renderer in renderers {
+        if renderer != RendererType::Default && !supported.contains(&renderer) {
+            continue;
+        }
+
+        if let Ok(bgfx) = init(renderer, vendor_id, device_id) {
+            let renderer = bgfx.get_renderer_type();
+            return Ok((bgfx, renderer));
+        }
+    }
+
+    Err(BgfxError::InitFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_formats() -> Vec<TextureFormat> {
+        let count = bgfx_sys::bgfx_texture_format::BGFX_TEXTURE_FORMAT_COUNT as u32;
+        (0..count)
+            .map(|format| unsafe { mem::transmute::<u32, TextureFormat>(format) })
+            .filter(|&format| {
+                format != TextureFormat::Unknown && format != TextureFormat::UnknownDepth
+            })
+            .collect()
+    }
+
+    #[test]
+    fn texture_format_metadata() {
+        assert!(TextureFormat::BC1.is_compressed());
+        assert!(!TextureFormat::BC1.is_depth());
+        assert!(TextureFormat::D24S8.is_depth());
+        assert!(!TextureFormat::RGBA8.is_compressed());
+        assert!(TextureFormat::RGBA8.supports_srgb());
+        assert!(!TextureFormat::RGBA16F.supports_srgb());
+        assert_eq!(TextureFormat::BC3.block_width(), 4);
+        assert_eq!(TextureFormat::BC3.block_height(), 4);
+        assert_eq!(TextureFormat::RGBA32F.bits_per_pixel(), 128);
+    }
+
+    #[test]
+    fn renderer_type_names_round_trip() {
+        for &renderer in RendererType::ALL.iter() {
+            assert_eq!(renderer.to_string().parse::<RendererType>().unwrap(), renderer);
+            assert_eq!(format!("{:?}", renderer).parse::<RendererType>().unwrap(), renderer);
+        }
+        assert_eq!("opengl".parse::<RendererType>().unwrap(), RendererType::OpenGL);
+        assert!("Glide".parse::<RendererType>().is_err());
+    }
+
+    #[test]
+    fn ticks_to_duration_uses_frequency() {
+        assert_eq!(ticks_to_duration(0, 1000), Duration::new(0, 0));
+        assert_eq!(ticks_to_duration(1500, 1000), Duration::new(1, 500_000_000));
+        assert_eq!(ticks_to_duration(3, 1_000_000), Duration::new(0, 3000));
+        assert_eq!(ticks_to_duration(42, 0), Duration::new(0, 0));
+    }
+
+    #[test]
+    fn calc_texture_size_matches_bgfx() {
+        let sizes = [(1, 1, 1), (4, 4, 1), (5, 3, 1), (64, 32, 1), (100, 75, 1), (256, 256, 1),
+                     (16, 16, 16), (33, 17, 9)];
+
+        for format in all_formats() {
+            for &(width, height, depth) in &sizes {
+                for &cube_map in &[false, true] {
+                    for &has_mips in &[false, true] {
+                        for &num_layers in &[1, 3] {
+                            let ours = TextureInfo::calc(format,
+                                                         width,
+                                                         height,
+                                                         depth,
+                                                         cube_map,
+                                                         has_mips,
+                                                         num_layers);
+
+                            let theirs = unsafe {
+                                let mut info: bgfx_sys::bgfx_texture_info_t = mem::zeroed();
+                                bgfx_sys::bgfx_calc_texture_size(&mut info,
+                                                                 width,
+                                                                 height,
+                                                                 depth,
+                                                                 cube_map,
+                                                                 has_mips,
+                                                                 num_layers,
+                                                                 mem::transmute(format));
+                                TextureInfo::from_raw(&info)
+                            };
+
+                            assert_eq!(ours, theirs);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn calc_texture_size_large() {
+        let info = TextureInfo::calc(TextureFormat::RGBA8, 16384, 16384, 1, false, false, 1);
+        assert_eq!(info.storage_size, 1 << 30);
+        assert_eq!(TextureFormat::RGBA32F.region_layout(std::u16::MAX, std::u16::MAX),
+                   (std::u16::MAX as u32 * 16, std::u16::MAX as u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in 16 bits")]
+    fn calc_texture_size_rounding_overflow() {
+        TextureInfo::calc(TextureFormat::BC1, std::u16::MAX, 4, 1, false, false, 1);
+    }
+}